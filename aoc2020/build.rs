@@ -2,7 +2,9 @@ use std::fs;
 use std::fs::DirEntry;
 
 fn main() {
+    println!("cargo:rerun-if-changed=src");
     let output_path = std::env::var("OUT_DIR").expect("No output dir?");
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("No manifest dir?");
     // Find the library with the biggest alphabetical name
 
     let src_dir = fs::read_dir("src")
@@ -10,7 +12,7 @@ fn main() {
         .filter_map(|entry| entry.ok())
         .collect::<Vec<_>>();
 
-    if let Some(build_content) = [get_furthest(&src_dir)]
+    if let Some(build_content) = [get_furthest(&src_dir), get_days(&src_dir, &manifest_dir)]
         .into_iter()
         .collect::<Option<Vec<_>>>()
     {
@@ -26,14 +28,47 @@ fn main() {
     }
 }
 
+fn day_numbers(lib_dir: &[DirEntry]) -> Vec<usize> {
+    let mut days = lib_dir
+        .iter()
+        .filter_map(|e| e.file_name().into_string().ok()) // dir name
+        .filter_map(|name| name.strip_prefix("day_").map(str::to_string))
+        .filter_map(|num| num.parse::<usize>().ok())
+        .collect::<Vec<_>>();
+    days.sort();
+    days
+}
+
 fn get_furthest(lib_dir: &[DirEntry]) -> Option<String> {
     Some(format!(
         "pub const FURTHEST_DAY: usize = {};",
-        lib_dir
-            .iter()
-            .filter_map(|e| e.file_name().into_string().ok()) // dir name
-            .map(|name| name.trim_start_matches("day_").to_string())
-            .filter_map(|num| num.parse::<usize>().ok())
-            .max()?
+        day_numbers(lib_dir).into_iter().max()?
     ))
-}
\ No newline at end of file
+}
+
+/// A module per day directory, and a table of their parts (`seq!` cannot count to a constant).
+fn get_days(lib_dir: &[DirEntry], manifest_dir: &str) -> Option<String> {
+    let days = day_numbers(lib_dir);
+    if days.is_empty() {
+        return None;
+    }
+    let modules = days
+        .iter()
+        .map(|day| {
+            format!(
+                "#[path = \"{}/src/day_{}/mod.rs\"]\npub mod day_{};",
+                manifest_dir, day, day
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let table = days
+        .iter()
+        .map(|day| format!("    ({}, [day_{}::part_1, day_{}::part_2]),", day, day, day))
+        .collect::<Vec<_>>()
+        .join("\n");
+    Some(format!(
+        "{}\n\n/// Every day with its parts, in order.\npub const DAYS: &[(u8, [SolverFn; 2])] = &[\n{}\n];",
+        modules, table
+    ))
+}
@@ -1,7 +1,7 @@
-pub(crate) fn part_1(input: &str) -> String {
+pub fn part_1(input: &str) -> String {
     todo!()
 }
 
-pub(crate) fn part_2(input: &str) -> String {
+pub fn part_2(input: &str) -> String {
     todo!()
 }
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub type SolverFn = fn(&str) -> String;
+
+include!(concat!(env!("OUT_DIR"), "/data_includes.rs")); // day modules, "FURTHEST_DAY" and "DAYS"
@@ -1,20 +1,24 @@
-use seq_macro::seq;
+use std::fs;
 
-mod day_1;
+use aoc2020::{DAYS, FURTHEST_DAY};
 
 fn main() {
-    if let
     print_furthest();
 }
 
-include!(concat!(env!("OUT_DIR"), "/data_includes.rs")); // imports "FURTHEST_DAY"
-
 pub fn print_furthest() {
-    let _: usize = FURTHEST_DAY; // = 1
-    seq!(N in 1..=FURTHEST_DAY {
+    DAYS.iter()
+        .filter(|(day, _)| *day as usize == FURTHEST_DAY)
+        .for_each(print_day);
+}
 
-        day_~N::part_1(fs::read_to_string("input.txt").unwrap().as_ref());
-    });
+pub fn print_all() {
+    DAYS.iter().for_each(print_day);
 }
 
-pub fn print_all() {}
\ No newline at end of file
+fn print_day((day, parts): &(u8, [aoc2020::SolverFn; 2])) {
+    let input = fs::read_to_string(format!("src/day_{}/input.txt", day)).unwrap();
+    for (index, part) in parts.iter().enumerate() {
+        println!("Day {} part {}: {}", day, index + 1, part(&input));
+    }
+}
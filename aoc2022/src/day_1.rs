@@ -30,20 +30,7 @@ impl Elf {
 pub struct Solution;
 impl Runnable for Solution {
     fn run_with_input(&self, input: String) {
-        let mut elves = Vec::new();
-        let mut new_foods = Vec::new();
-        for line in input.lines() {
-            if line.is_empty() {
-                elves.push(Elf::from_foods(new_foods));
-                new_foods = Vec::new();
-            } else {
-                let calories = line.parse::<u32>().expect("Could not convert to int!");
-                new_foods.push(FoodItem::from_calories(calories))
-            }
-        }
-
-        elves.sort_by_key(|b| std::cmp::Reverse(b.total_calories()));
-        let sorted_elves = elves;
+        let sorted_elves = sorted_elves(&input);
 
         println!(
             "Elf with the most calories has {} calories.",
@@ -58,11 +45,39 @@ impl Runnable for Solution {
             sorted_elves[2].total_calories()
         );
 
-        let top_calories: Vec<u32> = sorted_elves[..=2]
-            .iter()
-            .map(|e| e.total_calories())
-            .collect();
-        let total: u32 = top_calories.iter().sum();
-        println!("Calories of top three elves: {}", total)
+        println!(
+            "Calories of top three elves: {}",
+            top_three_calories(&sorted_elves)
+        )
+    }
+
+    fn answers(&self, input: &str) -> Vec<String> {
+        let sorted_elves = sorted_elves(input);
+        vec![
+            sorted_elves[0].total_calories().to_string(),
+            top_three_calories(&sorted_elves).to_string(),
+        ]
+    }
+}
+
+/// elves with the most calories first
+fn sorted_elves(input: &str) -> Vec<Elf> {
+    let mut elves = Vec::new();
+    let mut new_foods = Vec::new();
+    for line in input.lines() {
+        if line.is_empty() {
+            elves.push(Elf::from_foods(new_foods));
+            new_foods = Vec::new();
+        } else {
+            let calories = line.parse::<u32>().expect("Could not convert to int!");
+            new_foods.push(FoodItem::from_calories(calories))
+        }
     }
+
+    elves.sort_by_key(|b| std::cmp::Reverse(b.total_calories()));
+    elves
+}
+
+fn top_three_calories(sorted_elves: &[Elf]) -> u32 {
+    sorted_elves[..=2].iter().map(|e| e.total_calories()).sum()
 }
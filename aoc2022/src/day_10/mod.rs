@@ -9,6 +9,13 @@ impl Runnable for Solution {
         println!("PART 1:\n{}\n", part_1_solve(&input));
         println!("PART 2:\n{}\n", part_2_solve(&input));
     }
+
+    fn answers(&self, input: &str) -> Vec<String> {
+        vec![
+            part_1_solve(input).to_string(),
+            part_2_solve(input).to_string(),
+        ]
+    }
 }
 
 /// CPU only has one register and two types of instructions.
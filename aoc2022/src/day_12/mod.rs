@@ -6,6 +6,10 @@ impl Runnable for Solution {
 		println!("PART 1: {}", part_1_solve(&input));
 		// println!("PART 2: {}", part_2_solve(&input));
 	}
+
+	fn answers(&self, input: &str) -> Vec<String> {
+		vec![part_1_solve(input).to_string()]
+	}
 }
 /// Puzzle input is a heightmap, where heights are given by characters "a" to
 /// "z", where "z" is the highest.
@@ -10,6 +10,10 @@ impl Runnable for Solution {
 		println!("PART 1: {}", solve_part_1(&input));
 		// solve_part_2(input);
 	}
+
+	fn answers(&self, input: &str) -> Vec<String> {
+		vec![solve_part_1(input).to_string()]
+	}
 }
 
 /// We get a distress signal. The packets are decoded out of order.
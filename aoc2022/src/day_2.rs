@@ -5,8 +5,22 @@ impl Runnable for Solution {
     fn run_with_input(&self, input: String) {
         let str_rounds: Vec<&str> = input.lines().collect();
 
-        part_1(&str_rounds);
-        part_2(&str_rounds);
+        println!(
+            "Total points after all rounds using rules from part 1: {}",
+            part_1(&str_rounds)
+        );
+        println!(
+            "Total points after all rounds using rules from part 2: {}",
+            part_2(&str_rounds)
+        );
+    }
+
+    fn answers(&self, input: &str) -> Vec<String> {
+        let str_rounds: Vec<&str> = input.lines().collect();
+        vec![
+            part_1(&str_rounds).to_string(),
+            part_2(&str_rounds).to_string(),
+        ]
     }
 }
 
@@ -257,28 +271,18 @@ impl Round {
     }
 }
 
-#[allow(unused)]
-fn part_2(str_rounds: &[&str]) {
+fn part_2(str_rounds: &[&str]) -> u32 {
     let rounds_part_2: Vec<Round> = str_rounds
         .iter()
         .map(|r_s| Round::from_str_part_2(r_s).unwrap())
         .collect();
-    let points_part_2 = Round::player_total_points(&rounds_part_2);
-    println!(
-        "Total points after all rounds using rules from part 2: {}",
-        points_part_2
-    );
+    Round::player_total_points(&rounds_part_2)
 }
 
-#[allow(unused)]
-fn part_1(str_rounds: &[&str]) {
+fn part_1(str_rounds: &[&str]) -> u32 {
     let rounds_part_1: Vec<Round> = str_rounds
         .iter()
         .map(|r_s| Round::from_str_part_1(r_s).unwrap())
         .collect();
-    let points_part_1 = Round::player_total_points(&rounds_part_1);
-    println!(
-        "Total points after all rounds using rules from part 1: {}",
-        points_part_1
-    );
+    Round::player_total_points(&rounds_part_1)
 }
@@ -3,8 +3,18 @@ use crate::Runnable;
 pub struct Solution;
 impl Runnable for Solution {
     fn run_with_input(&self, input: String) {
-        part_1(&input);
-        part_2(&input);
+        println!(
+            "Sum of priority for all duplicate items: {}",
+            part_1(&input)
+        );
+        println!(
+            "Sum of priorities for badge of each group: {}",
+            part_2(&input)
+        );
+    }
+
+    fn answers(&self, input: &str) -> Vec<String> {
+        vec![part_1(input).to_string(), part_2(input).to_string()]
     }
 }
 
@@ -89,20 +99,17 @@ impl Rucksack {
 
 // what is the sum of the priorities (number value) of the duplicate item types?
 
-#[allow(unused)]
-fn part_1(input: &str) {
+fn part_1(input: &str) -> u32 {
     let rucksacks: Vec<Rucksack> = input.lines().map(Rucksack::from_str).collect();
 
-    let priority_sum: u32 = rucksacks
+    rucksacks
         .iter()
         .map(|r| {
             r.find_duplicate()
                 .unwrap_or_else(|| panic!("Rucksack '{:?}' does not contain any duplicates", r))
         })
         .map(|item| item.get_priority().unwrap())
-        .sum();
-
-    println!("Sum of priority for all duplicate items: {}", priority_sum);
+        .sum()
 }
 
 // part 2 //
@@ -165,8 +172,7 @@ impl Group {
 
 // what is the sum of the priorities (number values) for the badge of every group?
 
-#[allow(unused)]
-fn part_2(input: &str) {
+fn part_2(input: &str) -> u32 {
     // gather all groups
     let mut groups: Vec<Group> = Vec::new();
     let mut str_rucksacks: Vec<&str> = Vec::new();
@@ -194,7 +200,6 @@ fn part_2(input: &str) {
         })
         .collect();
 
-    // display sum of "priorities" (values) for each badge
-    let sum: u32 = badges.iter().map(|b| b.get_priority().unwrap()).sum();
-    println!("Sum of priorities for badge of each group: {}", sum)
+    // sum of "priorities" (values) for each badge
+    badges.iter().map(|b| b.get_priority().unwrap()).sum()
 }
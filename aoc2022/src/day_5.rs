@@ -16,10 +16,18 @@ use {
 pub struct Solution;
 impl Runnable for Solution {
     fn run_with_input(&self, input: String) {
-        // println!(
-        // "Crates on top of each stack after moves: {}",
-        part_1_solve(input.as_str());
-        // );
+        println!(
+            "Crates on top of each stack after moves: {}",
+            solve(input.as_str(), Piles::apply_1)
+        );
+        println!(
+            "Crates on top of each stack after moving several at once: {}",
+            solve(input.as_str(), Piles::apply_2)
+        );
+    }
+
+    fn answers(&self, input: &str) -> Vec<String> {
+        vec![solve(input, Piles::apply_1), solve(input, Piles::apply_2)]
     }
 }
 
@@ -61,7 +69,6 @@ impl fmt::Debug for Piles {
     }
 }
 impl Piles {
-    #[allow(unused)]
     fn apply_1(&mut self, ins: Instruction) {
         for _ in 0..ins.quantity {
             let el = self.0[ins.src].pop().unwrap();
@@ -104,7 +111,8 @@ fn parse_instruction(i: &str) -> IResult<&str, Instruction> {
     )(i)
 }
 
-fn part_1_solve(input: &str) {
+/// the crates on top of each pile, after moving them with `apply`
+fn solve(input: &str, apply: fn(&mut Piles, Instruction)) -> String {
     let mut lines = input.lines();
 
     let crate_lines: Vec<_> = lines
@@ -123,14 +131,11 @@ fn part_1_solve(input: &str) {
 
     for ins in lines.map(|line| all_consuming(parse_instruction)(line).finish().unwrap().1) {
         println!("{ins:?}");
-        piles.apply_2(ins);
+        apply(&mut piles, ins);
         println!("{piles:?}");
     }
 
-    println!(
-        "answer = {}",
-        piles.0.iter().map(|pile| pile.last().unwrap()).join("")
-    );
+    piles.0.iter().map(|pile| pile.last().unwrap()).join("")
 }
 
 fn transpose_rev<T>(v: Vec<Vec<Option<T>>>) -> Vec<Vec<T>> {
@@ -5,18 +5,25 @@ use crate::Runnable;
 pub(crate) struct Solution;
 impl Runnable for Solution {
     fn run_with_input(&self, input: String) {
-        part_1_solve(input.as_str());
-        part_2_solve(input.as_str());
+        println!("answer (part 1) = {}", part_1_solve(input.as_str()));
+        println!("answer (part 2) = {}", part_2_solve(input.as_str()));
+    }
+
+    fn answers(&self, input: &str) -> Vec<String> {
+        vec![
+            part_1_solve(input).to_string(),
+            part_2_solve(input).to_string(),
+        ]
     }
 }
 
 // find first "start-of-packet" but remember how many characters it took to find it.
-fn part_1_solve(input: &str) {
-    println!("answer (part 1) = {}", find_marker(input, 4).unwrap());
+fn part_1_solve(input: &str) -> usize {
+    find_marker(input, 4).unwrap()
 }
 
-fn part_2_solve(input: &str) {
-    println!("answer (part 2) = {}", find_marker(input, 14).unwrap())
+fn part_2_solve(input: &str) -> usize {
+    find_marker(input, 14).unwrap()
 }
 
 fn find_marker(input: &str, sequence_size: usize) -> Option<usize> {
@@ -17,13 +17,26 @@ pub struct Solution;
 impl Runnable for Solution {
     fn run_with_input(&self, input: String) {
         let input = input.as_str();
-        part_1_solve(input);
-        part_2_solve(input);
+        println!(
+            "Sum of directories under size 100_000: {}",
+            part_1_solve(input)
+        );
+        println!(
+            "Size of smallest directory that gives enough space for update: {}",
+            part_2_solve(input)
+        );
+    }
+
+    fn answers(&self, input: &str) -> Vec<String> {
+        vec![
+            part_1_solve(input).to_string(),
+            part_2_solve(input).to_string(),
+        ]
     }
 }
 
 // find all directories with a size of less than or exactly 100_000, then calculate the sum of their sizes.
-fn part_1_solve(input: &str) {
+fn part_1_solve(input: &str) -> u64 {
     let lines = input
         .lines()
         .map(|l| all_consuming(parse_line)(l).finish().unwrap().1);
@@ -31,13 +44,12 @@ fn part_1_solve(input: &str) {
     let (dir_tree, root_id) = construct_dir_tree(lines);
 
     // find all directories in tree with a size <=100_000, then sum them together
-    let sum_of_small_ish_directories = dir_tree
+    dir_tree
         .traverse_pre_order(&root_id)
         .expect("could not traverse tree?")
         .map(|dir_n| recursive_dir_size(&dir_tree, dir_n))
         .filter(|&size| size <= 100_000)
-        .sum::<u64>();
-    println!("Sum of directories under size 100_000: {sum_of_small_ish_directories}");
+        .sum::<u64>()
 }
 
 fn construct_dir_tree(lines: impl Iterator<Item = Line>) -> (Tree<Directory>, id_tree::NodeId) {
@@ -199,7 +211,7 @@ fn parse_line(i: &str) -> IResult<&str, Line> {
 }
 
 // find the smallest directory that would free up enough space for NEEDED_MIN_DISK_SPACE
-fn part_2_solve(input: &str) {
+fn part_2_solve(input: &str) -> u64 {
     let lines = input
         .lines()
         .map(|l| all_consuming(parse_line)(l).finish().unwrap().1);
@@ -216,14 +228,11 @@ fn part_2_solve(input: &str) {
         .checked_sub(free_space)
         .expect("free space exceeded needed space?");
 
-    let smallest_fit_dir_size = dir_tree
+    dir_tree
         .traverse_pre_order(&root_id)
         .expect("could not traverse tree?")
         .map(|dir_n| recursive_dir_size(&dir_tree, dir_n))
         .filter(|&s| s >= min_space_to_free)
         .min()
-        .expect("No value found in iterator?");
-    println!(
-        "Size of smallest directory that gives enough space for update: {smallest_fit_dir_size}"
-    );
+        .expect("No value found in iterator?")
 }
@@ -14,6 +14,13 @@ impl Runnable for Solution {
             part_2_solve(input)
         );
     }
+
+    fn answers(&self, input: &str) -> Vec<String> {
+        vec![
+            part_1_solve(input).to_string(),
+            part_2_solve(input).to_string(),
+        ]
+    }
 }
 
 // how many trees are visible outside the grid?
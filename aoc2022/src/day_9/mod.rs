@@ -10,6 +10,13 @@ impl Runnable for Solution {
 		println!("PART 1: {}", part_1_solve(input));
 		println!("PART 2: {}", part_2_solve(input));
 	}
+
+	fn answers(&self, input: &str) -> Vec<String> {
+		vec![
+			part_1_solve(input).to_string(),
+			part_2_solve(input).to_string(),
+		]
+	}
 }
 
 /// imagine/simulate head of rope moving on 2D grid, and we can determine how
@@ -237,7 +244,7 @@ fn part_2_solve(input: &str) -> usize {
 		for _ in 0..motion.repeats {
 			let new_head_pos = snake.pos.after_dir(&motion.dir);
 			snake.move_recursive(&new_head_pos);
-			println!("\n\n--- SNAKE ---\n\n{}", snake);
+			util::viz::frame(&snake);
 
 			let new_positions = snake
 				.collection_recursive()
@@ -16,6 +16,12 @@ mod day_9;
 
 trait Runnable {
 	fn run_with_input(&self, input: String);
+
+	/// answers of the solved parts in order, for running alongside other years.
+	/// days that only print their answers have none.
+	fn answers(&self, _input: &str) -> Vec<String> {
+		Vec::new()
+	}
 }
 struct Configuration {
 	runnable: Box<dyn Runnable>,
@@ -37,6 +43,21 @@ impl Configuration {
 		self.runnable.run_with_input(input);
 	}
 
+	/// prints "PART N: answer" per part, with any further lines of an answer indented by a tab,
+	/// after whatever the solvers print themselves
+	fn print_answers(&self) {
+		let input = self
+			.get_input_from_number()
+			.unwrap_or_else(|_| panic!("Input missing for day {}", self.number));
+		for (index, answer) in self.runnable.answers(&input).iter().enumerate() {
+			let mut lines = answer.lines();
+			println!("PART {}: {}", index + 1, lines.next().unwrap_or_default());
+			for line in lines {
+				println!("\t{}", line);
+			}
+		}
+	}
+
 	fn get_input_from_number(&self) -> std::io::Result<String> {
 		let path = format!("inputs/day_{}.txt", self.number);
 		fs::read_to_string(path)
@@ -107,18 +128,34 @@ fn main() {
 	args.next(); // discard first element since it isn't user-relevant
 
 	let selection_arg = args.next(); // argument 1 determines what configuration to run
-	let try_run_as_test = match args.next() {
-		// argument 2 determines if it should try and use test input
-		Some(val) => matches!(val.as_str(), "test"),
-		_ => false
-	};
+	let mode_arg = args.next();
+	// argument 2 determines if it should try and use test input
+	let try_run_as_test = matches!(mode_arg.as_deref(), Some("test"));
+	// or if it should only print the answers, for the runner of every year
+	let only_answers = matches!(mode_arg.as_deref(), Some("answers"));
+
+	// frames pushed by solvers are played to AOC_VIZ: "-" for the terminal, or a .gif/.png file
+	let viz_output = env::var("AOC_VIZ")
+		.ok()
+		.map(|output| {
+			output
+				.parse::<util::viz::Output>()
+				.expect("AOC_VIZ should be -, or a .gif or .png file")
+		});
+	if viz_output.is_some() {
+		util::viz::record(util::viz::CAPACITY);
+	}
 
 	if let Some(selection) = selection_arg {
 		// run a specific configuration
 		for configuration in &all_configurations {
 			if selection.as_str() == configuration.number.to_string().as_str() {
-				configuration.run(try_run_as_test);
-				println!();
+				if only_answers {
+					configuration.print_answers();
+				} else {
+					configuration.run(try_run_as_test);
+					println!();
+				}
 			}
 		}
 	} else {
@@ -127,4 +164,10 @@ fn main() {
 			c.run_with_input(c.get_input_from_number().expect("Could not find input!"))
 		});
 	}
+
+	if let (Some(output), Some(recorder)) = (viz_output, util::viz::finish()) {
+		output
+			.play(recorder.frames(), 30)
+			.expect("could not play recorded frames");
+	}
 }
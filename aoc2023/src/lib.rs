@@ -15,6 +15,13 @@ pub fn use_all_days(_item: TokenStream) -> TokenStream {
 		.unwrap()
 }
 
+#[proc_macro]
+pub fn all_days(_item: TokenStream) -> TokenStream {
+	format!("[{}]", REST_DAYS.chain(INDEPENDENT_DAYS).join(", "))
+		.parse()
+		.unwrap()
+}
+
 #[proc_macro]
 pub fn two_digit(num: TokenStream) -> TokenStream {
 	format!("{:0>2}", num.to_string()).parse().unwrap()
@@ -0,0 +1,18 @@
+//! Generated by `aoc ingest` from the puzzle descriptions.
+//! Ingesting again overwrites this file.
+
+#[test]
+fn p1_example_solvable() {
+    let mut registry = util::Registry::new();
+    crate::register(&mut registry);
+    let answer = registry.answer(1, 1, include_str!("P1_EXAMPLE"));
+    assert_eq!(answer.expect("part is registered").unwrap(), "11");
+}
+
+#[test]
+fn p2_example_solvable() {
+    let mut registry = util::Registry::new();
+    crate::register(&mut registry);
+    let answer = registry.answer(1, 2, include_str!("P2_EXAMPLE"));
+    assert_eq!(answer.expect("part is registered").unwrap(), "31");
+}
@@ -0,0 +1,12 @@
+mod common;
+pub mod p1;
+pub mod p2;
+mod parse;
+#[cfg(test)]
+mod examples;
+
+pub fn register(registry: &mut util::Registry) {
+    registry.register(1, 1, |input| p1::solve(input));
+    registry.register(1, 2, |input| p2::solve(input));
+    registry.normalize(1, util::Normalize::ALL);
+}
@@ -3,17 +3,13 @@
 #[allow(unused_imports)]
 use winnow::{ascii::*, combinator::*, error::*, prelude::*, token::*, Parser};
 
-mod common;
-mod parse;
+use util::ParseReport;
 
-#[cfg_attr(test, allow(unused))]
-fn main() {
-    util::DayInput::find::<1>().solve_with(solve);
-}
+use crate::parse;
 
-fn solve(input: impl AsRef<str>) -> u32 {
-    let mut data = parse::parse_lists
-        .parse(input.as_ref())
+pub fn solve(input: impl AsRef<str>) -> u32 {
+    let mut data = terminated(parse::parse_lists, eof)
+        .parse_report(input.as_ref())
         .expect("needs to be parsable");
     data.0.sort();
     data.1.sort();
@@ -1,22 +1,16 @@
 #![doc = include_str!("../p2.md")]
 
-#[cfg(test)]
-mod common;
-mod parse;
+use crate::parse;
 
 use std::collections::HashMap;
+use util::ParseReport;
 
 #[allow(unused_imports)]
 use winnow::{ascii::*, combinator::*, error::*, prelude::*, token::*, Parser};
 
-#[cfg_attr(test, allow(unused))]
-fn main() {
-    util::DayInput::find::<1>().solve_with(solve);
-}
-
-fn solve(input: impl AsRef<str>) -> u32 {
+pub fn solve(input: impl AsRef<str>) -> u32 {
     let (left, right) = parse::parse_lists
-        .parse_next(&mut input.as_ref())
+        .parse_report(input.as_ref())
         .expect("input still parsable");
 
     // find how many times each number in list 0 appears in list 1, then
@@ -1,4 +1,7 @@
+use std::fmt::{Display, Formatter};
+
 use itertools::Itertools;
+use util::{grid::Coord, svg::Picture};
 use winnow::{
     ascii::line_ending,
     combinator::{alt, repeat, separated},
@@ -46,6 +49,61 @@ impl TopographicMap {
             }
         })
     }
+
+    /// Every hiking trail from `trailhead`, as its positions from height 0 to 9.
+    pub fn trails_from(&self, trailhead: usize) -> Vec<Vec<usize>> {
+        let map_width = self.width as usize;
+        let mut trails = vec![vec![trailhead]];
+        for one_higher in 1..=9 {
+            trails = trails
+                .into_iter()
+                .flat_map(|trail| {
+                    let last = *trail.last().expect("trails start at their trailhead");
+                    self.all_dir_iter(last, one_higher, map_width)
+                        .map(move |next| [trail.as_slice(), &[next]].concat())
+                })
+                .collect();
+        }
+        trails
+    }
+
+    /// Every hiking trail in the color of its trailhead,
+    /// which is labeled with its score.
+    pub fn to_svg(&self) -> Picture {
+        let map_width = self.width as usize;
+        let coord = |pos: usize| Coord::new(pos % map_width, pos / map_width);
+        let mut picture = Picture::from_text(self);
+        for (n, trailhead) in self.trailheads().enumerate() {
+            let color = util::svg::distinct(n);
+            let trails = self.trails_from(trailhead);
+            for trail in &trails {
+                picture.path(trail.iter().copied().map(coord), color);
+            }
+            let score = trails
+                .iter()
+                .filter_map(|trail| trail.last())
+                .unique()
+                .count();
+            picture.label(coord(trailhead), score, color);
+        }
+        picture
+    }
+}
+impl Display for TopographicMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.inner.chunks(self.width as usize).enumerate() {
+            if y != 0 {
+                writeln!(f)?;
+            }
+            for location in row {
+                match location.0 {
+                    Some(height) => write!(f, "{}", height)?,
+                    None => write!(f, ".")?,
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 /// In the problem this is called the "position"
@@ -1,6 +1,7 @@
 #![doc = include_str!("../p1.md")]
 
 use itertools::Itertools;
+use util::ParseReport;
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -12,12 +13,7 @@ use winnow::{
     {PResult, Parser},
 };
 
-mod common;
-use common::*;
-
-fn main() {
-    util::DayInput::find::<10>().solve_with(solve);
-}
+use crate::common::{self, *};
 
 /// # Definitions
 /// Hiking trails increment by exactly 1 every step. Thy start from 0 and go to
@@ -39,14 +35,15 @@ fn main() {
 /// (such that they are distinct), then iterate from every start trail and go
 /// every possible cardinal direction each step. The input isn't enormous, so
 /// this doesn't feel too infeasible.
-fn solve(input: impl AsRef<str>) -> u64 {
+pub fn solve(input: impl AsRef<str>) -> u64 {
     let map = common::parse_map
-        .parse_next(&mut input.as_ref())
+        .parse_report(input.as_ref())
         .expect("parsable");
     debug_assert_eq!(
         input.as_ref().trim().lines().count() * map.width as usize,
         map.inner.len()
     );
+    util::svg::export("day10-trails", || map.to_svg());
     trailhead_score_sum(&map)
 }
 
@@ -97,6 +94,6 @@ mod tests {
     #[ignore]
     #[test]
     fn input_solvable() {
-        assert_eq!(super::solve(include_str!("../../inputs/10")), 607);
+        assert_eq!(super::solve(util::input_or_skip!(10)), 607);
     }
 }
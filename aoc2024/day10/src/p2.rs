@@ -1,8 +1,7 @@
 #![doc = include_str!("../p2.md")]
 
-mod common;
-
-use common::*;
+use crate::common::*;
+use util::ParseReport;
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -12,10 +11,6 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<10>().solve_with(solve);
-}
-
 /// # Problem
 /// What is the sum of the trailhead ratings?
 /// # Definitions
@@ -29,8 +24,8 @@ fn main() {
 /// We want to start collecting data on each journey as we go back up the tree,
 /// *not* as we go down. It remains true that branch depth is still capped to 9
 /// or 10.
-fn solve(input: impl AsRef<str>) -> u64 {
-    let map = parse_map.parse_next(&mut input.as_ref()).expect("parsable");
+pub fn solve(input: impl AsRef<str>) -> u64 {
+    let map = parse_map.parse_report(input.as_ref()).expect("parsable");
     sum_of_distinct_hiking_trails(&map)
 }
 
@@ -64,8 +59,21 @@ mod tests {
         assert_eq!(super::solve(include_str!("EXAMPLE_LARGE")), 81);
     }
 
+    #[test]
+    fn listed_trails_match_ratings() {
+        use util::ParseReport;
+        let map = crate::common::parse_map
+            .parse_report(include_str!("EXAMPLE_LARGE"))
+            .unwrap();
+        let trails = map
+            .trailheads()
+            .map(|trailhead| map.trails_from(trailhead).len())
+            .sum::<usize>();
+        assert_eq!(trails, 81);
+    }
+
     #[test]
     fn input_solvable() {
-        assert_eq!(super::solve(include_str!("../../inputs/10")), 1384);
+        assert_eq!(super::solve(util::input_or_skip!(10)), 1384);
     }
 }
@@ -0,0 +1,11 @@
+//! Generated by `aoc ingest` from the puzzle descriptions.
+//! Ingesting again overwrites this file.
+
+#[test]
+#[ignore = "panics"]
+fn p1_example_solvable() {
+    let mut registry = util::Registry::new();
+    crate::register(&mut registry);
+    let answer = registry.answer(11, 1, include_str!("P1_EXAMPLE"));
+    assert_eq!(answer.expect("part is registered").unwrap(), "55312");
+}
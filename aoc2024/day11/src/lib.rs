@@ -0,0 +1,10 @@
+mod common;
+pub mod p1;
+pub mod p2;
+#[cfg(test)]
+mod examples;
+
+pub fn register(registry: &mut util::Registry) {
+    registry.register(11, 1, |input| p1::solve(input));
+    registry.register(11, 2, |input| p2::solve(input));
+}
@@ -1,5 +1,6 @@
 #![doc = include_str!("../p1.md")]
 
+use util::ParseReport;
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -9,13 +10,8 @@ use winnow::{
     {PResult, Parser},
 };
 
-mod common;
 use crate::common::*;
 
-fn main() {
-    util::DayInput::find::<11>().solve_with(solve);
-}
-
 /// # Problem
 /// A number may change or splits into two numbers, shifting other numbers
 /// (linear sequence) All the numbers change "at once" (during each update) and
@@ -31,16 +27,14 @@ fn main() {
 /// - For other numbers, the number is multiplied by 2024
 ///
 /// The order is preserved. Splitting does not change this.
-fn solve(input: impl AsRef<str>) -> u64 {
-    let initial = parse_stones
-        .parse_next(&mut input.as_ref())
-        .expect("parsable");
+pub fn solve(input: impl AsRef<str>) -> u64 {
+    let initial = parse_stones.parse_report(input.as_ref()).expect("parsable");
     after_n_blinks::<25>(initial).0.len() as u64
 }
 
 fn after_n_blinks<const N: u8>(mut stones: Stones) -> Stones {
     for blinks in 0..N {
-        println!("Blink level: {}", blinks);
+        log::debug!("Blink level: {}", blinks);
         stones = after_blink(stones);
     }
     stones
@@ -112,6 +106,6 @@ mod tests {
 
     #[test]
     fn input_solvable() {
-        assert_eq!(solve(include_str!("../../inputs/11")), 186996);
+        assert_eq!(solve(util::input_or_skip!(11)), 186996);
     }
 }
@@ -1,10 +1,9 @@
 #![doc = include_str!("../p2.md")]
 
-mod common;
-
 use std::collections::HashMap;
 
-use common::*;
+use crate::common::*;
+use util::ParseReport;
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -14,12 +13,8 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<11>().solve_with(solve);
-}
-
-fn solve(input: impl AsRef<str>) -> u64 {
-    let initial = parse_stones(&mut input.as_ref()).expect("parsable");
+pub fn solve(input: impl AsRef<str>) -> u64 {
+    let initial = parse_stones.parse_report(input.as_ref()).expect("parsable");
     len_after_blinks::<75>(initial)
 }
 
@@ -118,12 +113,12 @@ mod tests {
     #[test]
     fn input_solvable() {
         assert_ne!(
-            super::solve(include_str!("../../inputs/11")),
+            super::solve(util::input_or_skip!(11)),
             613318790,
             "number is too low"
         );
         assert_eq!(
-            super::solve(include_str!("../../inputs/11")),
+            super::solve(util::input_or_skip!(11)),
             221683913164898,
             "number is too low"
         );
@@ -0,0 +1,11 @@
+pub mod p1;
+mod p1_garden;
+mod p1_regions;
+pub mod p2;
+#[cfg(test)]
+mod examples;
+
+pub fn register(registry: &mut util::Registry) {
+    registry.register(12, 1, |input| p1::solve(input));
+    registry.register(12, 2, |input| p2::solve(input));
+}
@@ -6,6 +6,7 @@ use std::{
 
 use colored::{Color, Colorize};
 use itertools::Itertools;
+use util::{grid::Coord, svg::Picture, ParseReport};
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -98,8 +99,8 @@ impl Garden {
             this_pos: Position,
             visited: &mut HashSet<Position>,
         ) -> (Region, Vec<Region>) {
-            eprintln!(
-                "All visited:\n{}\n",
+            log::trace!(
+                "All visited:\n{}",
                 garden.as_highlighted(
                     &Region {
                         flower: Flower('X'),
@@ -187,6 +188,22 @@ impl Garden {
         self.inner.len() > pos.0
     }
 
+    /// Every region in its own color, labeled with its area on its first flower.
+    pub fn to_svg(&self, regions: &[Region]) -> Picture {
+        let coord = |pos: &Position| Coord::new(pos.0 % self.width, pos.0 / self.width);
+        let mut picture = Picture::from_text(self);
+        for (n, region) in regions.iter().enumerate() {
+            let color = util::svg::distinct(n);
+            for pos in &region.positions {
+                picture.fill(coord(pos), color);
+            }
+            if let Some(first) = region.positions.iter().min() {
+                picture.label(coord(first), region.positions.len(), [255, 255, 255]);
+            }
+        }
+        picture
+    }
+
     #[allow(unused)]
     pub fn as_highlighted<'a>(&'a self, region: &'a Region, color: Color) -> RegionHighlight<'a> {
         RegionHighlight {
@@ -197,10 +214,10 @@ impl Garden {
     }
 }
 impl FromStr for Garden {
-    type Err = ErrMode<ContextError>;
+    type Err = util::ParseError;
 
-    fn from_str(mut s: &str) -> Result<Self, Self::Err> {
-        parse_garden.parse_next(&mut s)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_garden.parse_report(s)
     }
 }
 pub struct RegionHighlight<'a> {
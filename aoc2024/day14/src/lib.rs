@@ -0,0 +1,13 @@
+pub mod p1;
+pub mod p2;
+#[cfg(test)]
+mod examples;
+
+use p1::{STANDARD_HEIGHT, STANDARD_WIDTH};
+
+pub fn register(registry: &mut util::Registry) {
+    registry.register(14, 1, |input| {
+        p1::solve::<STANDARD_WIDTH, STANDARD_HEIGHT>(input)
+    });
+    registry.register(14, 2, |input| p2::solve(input));
+}
@@ -3,6 +3,7 @@
 use itertools::Itertools;
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
+use util::ParseReport;
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -14,11 +15,8 @@ use winnow::{
     {PResult, Parser},
 };
 
-const STANDARD_WIDTH: Scalar = 101;
-const STANDARD_HEIGHT: Scalar = 103;
-fn main() {
-    util::DayInput::find::<14>().solve_with(solve::<STANDARD_WIDTH, STANDARD_HEIGHT>);
-}
+pub const STANDARD_WIDTH: Scalar = 101;
+pub const STANDARD_HEIGHT: Scalar = 103;
 
 /// # Problem
 /// Robots are in a (grid) space.
@@ -30,11 +28,9 @@ fn main() {
 /// and this works as long as positions are 0-indexed.
 /// Quadrants can be used to semantically group-map robots by their position.
 /// This is done using component comparison.
-fn solve<const W: Scalar, const H: Scalar>(input: impl AsRef<str>) -> u64 {
+pub fn solve<const W: Scalar, const H: Scalar>(input: impl AsRef<str>) -> u64 {
     let mut space = Space::<W, H> {
-        robots: parse_input
-            .parse_next(&mut input.as_ref())
-            .expect("parsable"),
+        robots: parse_input.parse_report(input.as_ref()).expect("parsable"),
     };
     const STANDARD_MOVES: usize = 100;
     space.move_robots::<STANDARD_MOVES>();
@@ -49,7 +45,8 @@ impl<const WIDTH: Scalar, const HEIGHT: Scalar> Space<WIDTH, HEIGHT> {
     //    const WIDTH: Scalar = W;
     //    const HEIGHT: Scalar = H;
     fn move_robots<const MOVES: usize>(&mut self) {
-        eprintln!("Space BEFORE move(s):\n{}", self);
+        log::trace!("Space BEFORE move(s):\n{}", self);
+        util::viz::frame(&*self);
         for _i in 0..MOVES {
             // say, 100 times
             for robot in self.robots.iter_mut() {
@@ -57,12 +54,13 @@ impl<const WIDTH: Scalar, const HEIGHT: Scalar> Space<WIDTH, HEIGHT> {
                 robot.pos.x = (robot.pos.x + robot.vel.x).rem_euclid(WIDTH);
                 robot.pos.y = (robot.pos.y + robot.vel.y).rem_euclid(HEIGHT);
             }
+            util::viz::frame(&*self);
         }
-        eprintln!("Space after {} move(s):\n{}", MOVES, self);
+        log::trace!("Space after {} move(s):\n{}", MOVES, self);
         //	    eprintln!("Robots after move(s):\n{}\n", self.as_robots());
     }
     fn safety_factor(&self) -> u64 {
-        eprintln!("Calculating safety:\n{}\n", self.as_quads());
+        log::trace!("Calculating safety:\n{}", self.as_quads());
         let middle_x = const { (HEIGHT - 1) / 2 };
         let middle_y = const { (HEIGHT - 1) / 2 };
         self.robots
@@ -217,7 +215,7 @@ mod tests {
 
     #[test]
     fn example_solvable() {
-        use crate::Scalar;
+        use super::Scalar;
         const EXAMPLE_WIDTH: Scalar = 11;
         const EXAMPLE_HEIGHT: Scalar = 7;
         assert_eq!(
@@ -231,12 +229,12 @@ mod tests {
     fn input_solvable() {
         use super::{STANDARD_HEIGHT, STANDARD_WIDTH};
         assert_ne!(
-            super::solve::<STANDARD_WIDTH, STANDARD_HEIGHT>(include_str!("../../inputs/14")),
+            super::solve::<STANDARD_WIDTH, STANDARD_HEIGHT>(util::input_or_skip!(14)),
             230357106,
             "too high"
         );
         assert_eq!(
-            super::solve::<STANDARD_WIDTH, STANDARD_HEIGHT>(include_str!("../../inputs/14")),
+            super::solve::<STANDARD_WIDTH, STANDARD_HEIGHT>(util::input_or_skip!(14)),
             0,
             "unknown"
         );
@@ -2,6 +2,8 @@
 
 use itertools::Itertools;
 use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use util::ParseReport;
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -13,10 +15,6 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<15>().solve_with(solve);
-}
-
 /// # Problem
 /// Simulate a robot moving around a warehouse of (immovable) walls and (movable) boxes.
 /// What is the sum of the "GPS coordinates" for each box?
@@ -34,23 +32,27 @@ fn main() {
 /// A box can be moved but cannot enter a wall. If a robot move would move a box into the wall,
 /// it does not happen.
 /// There are also empty spaces in the warehouse (`.`).
-fn solve(input: impl AsRef<str>) -> u64 {
-    let (mut warehouse, moves) = parse.parse_next(&mut input.as_ref()).expect("parsable");
+pub fn solve(input: impl AsRef<str>) -> u64 {
+    let (mut warehouse, moves) = parse.parse_report(input.as_ref()).expect("parsable");
+    util::viz::frame(&warehouse);
     for robot_move in moves {
-        warehouse.process_move(&robot_move)
+        warehouse.process_move(&robot_move);
+        util::viz::frame(&warehouse);
     }
     warehouse
         .boxes
         .iter()
         .map(|box_pos| {
-            100 * (1 + (box_pos.0 / warehouse.width)) as u64 + (box_pos.0 % warehouse.width) as u64
+            // the implicit edges are one space away from the top and left
+            100 * (1 + (box_pos.0 / warehouse.width)) as u64
+                + (1 + (box_pos.0 % warehouse.width)) as u64
         })
         .sum()
 }
 
 impl Warehouse {
     fn process_move(&mut self, dir: &Direction) {
-        if let Some(walk_pos) = self.robot.checked_move(self.width, dir) {
+        if let Some(walk_pos) = self.robot.checked_move(self.width, self.height, dir) {
             if !self.walls.contains(&walk_pos) {
                 // no walls, we may push or just move
                 if !self.boxes.contains(&walk_pos) {
@@ -58,11 +60,13 @@ impl Warehouse {
                     self.robot = walk_pos;
                 } else {
                     // we may be able to push
-                    let mut target_pos = walk_pos.checked_move(self.width, dir);
-                    while target_pos.is_some_and(|pos| self.boxes.contains(&pos)) {
-                        target_pos = walk_pos.checked_move(self.width, dir);
+                    let mut target_pos = walk_pos.checked_move(self.width, self.height, dir);
+                    while let Some(pos) = target_pos.filter(|pos| self.boxes.contains(pos)) {
+                        target_pos = pos.checked_move(self.width, self.height, dir);
                     }
-                    if let Some(end_pos) = target_pos.filter(|pos| self.walls.contains(pos)) {
+                    // the whole row of boxes moves, which is the same as the first one
+                    // moving to the end of it
+                    if let Some(end_pos) = target_pos.filter(|pos| !self.walls.contains(pos)) {
                         self.boxes.insert(end_pos);
                         self.boxes.remove(&walk_pos);
                         self.robot = walk_pos;
@@ -73,12 +77,15 @@ impl Warehouse {
     }
 }
 impl Pos {
-    fn checked_move(&self, width: usize, dir: &Direction) -> Option<Self> {
+    /// The position next to this one, unless that is in the edge of the map.
+    fn checked_move(&self, width: usize, height: usize, dir: &Direction) -> Option<Self> {
+        let (x, y) = (self.0 % width, self.0 / width);
         match dir {
-            Direction::Up => self.0.checked_sub(width),
-            Direction::Down => self.0.checked_add(width),
-            Direction::Left => self.0.checked_sub(1),
-            Direction::Right => self.0.checked_add(1),
+            Direction::Up if y > 0 => Some(self.0 - width),
+            Direction::Down if y + 1 < height => Some(self.0 + width),
+            Direction::Left if x > 0 => Some(self.0 - 1),
+            Direction::Right if x + 1 < width => Some(self.0 + 1),
+            _ => None,
         }
         .map(Pos)
     }
@@ -95,10 +102,14 @@ enum Direction {
     Left,
     Right,
 }
+/// Moves are split over several lines.
 fn parse_moves(input: &mut &str) -> PResult<Vec<Direction>> {
-    repeat(1.., opt(parse_direction))
-        .map(|v: Vec<_>| v.into_iter().flatten().collect())
-        .parse_next(input)
+    repeat(
+        1..,
+        alt((parse_direction.map(Some), line_ending.value(None))),
+    )
+    .map(|v: Vec<_>| v.into_iter().flatten().collect())
+    .parse_next(input)
 }
 fn parse_direction(input: &mut &str) -> PResult<Direction> {
     alt((
@@ -118,6 +129,34 @@ struct Warehouse {
     walls: HashSet<Pos>,
     robot: Pos,
     width: usize,
+    height: usize,
+}
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let edge = "#".repeat(self.width + 2);
+        writeln!(f, "{}", edge)?;
+        for y in 0..self.height {
+            write!(f, "#")?;
+            for x in 0..self.width {
+                let pos = Pos(y * self.width + x);
+                write!(
+                    f,
+                    "{}",
+                    if self.robot == pos {
+                        '@'
+                    } else if self.walls.contains(&pos) {
+                        '#'
+                    } else if self.boxes.contains(&pos) {
+                        'O'
+                    } else {
+                        '.'
+                    }
+                )?;
+            }
+            writeln!(f, "#")?;
+        }
+        write!(f, "{}", edge)
+    }
 }
 #[derive(Copy, Clone, Eq, PartialEq)]
 enum MapItem {
@@ -130,13 +169,17 @@ fn parse_warehouse(input: &mut &str) -> PResult<Warehouse> {
     delimited(
         (parse_horizontal_edge, line_ending),
         separated(1.., parse_horizontal_inner, line_ending).map(|v: Vec<Vec<_>>| {
-            debug_assert!(v.iter().map(|v| v.len()).all_equal()); // warn:
-                                                                  // this crashes while parsing
+            debug_assert!(v.iter().map(|v| v.len()).all_equal());
             let width = v[0].len();
+            let height = v.len();
             let (boxes, interior_walls, robot) = v
                 .into_iter()
                 .enumerate()
-                .flat_map(|(y, xs)| xs.into_iter().map(move |(x, variant)| (y * x, variant)))
+                .flat_map(|(y, xs)| {
+                    xs.into_iter()
+                        .enumerate()
+                        .map(move |(x, variant)| (y * width + x, variant))
+                })
                 .fold(
                     (HashSet::new(), HashSet::new(), None),
                     |(mut boxes, mut walls, mut robot), (pos, variant)| {
@@ -151,7 +194,7 @@ fn parse_warehouse(input: &mut &str) -> PResult<Warehouse> {
                                 debug_assert_eq!(robot, None);
                                 robot = Some(Pos(pos));
                             }
-                            _ => unreachable!("empty is discarded earlier"),
+                            MapItem::Empty => {}
                         }
                         (boxes, walls, robot)
                     },
@@ -161,27 +204,21 @@ fn parse_warehouse(input: &mut &str) -> PResult<Warehouse> {
                 walls: interior_walls,
                 robot: robot.expect("should be a robot somewhere"),
                 width,
+                height,
             }
         }),
-        parse_horizontal_edge,
+        (line_ending, parse_horizontal_edge),
     )
     .parse_next(input)
 }
-fn parse_horizontal_inner(input: &mut &str) -> PResult<Vec<(usize, MapItem)>> {
+/// Every item between the edges, empty ones included such that a row is as long as the map is wide.
+fn parse_horizontal_inner(input: &mut &str) -> PResult<Vec<MapItem>> {
     trace(
         "parsing middle line",
         (
             delimited(
                 '#',
-                trace(
-                    "reading map items (inner)",
-                    repeat(0.., parse_item).map(|v: Vec<_>| {
-                        v.into_iter()
-                            .enumerate()
-                            .filter(|&(_, variant)| variant != MapItem::Empty)
-                            .collect()
-                    }),
-                ),
+                trace("reading map items (inner)", repeat(0.., parse_item)),
                 '#',
             ),
             trace("leave early", not((line_ending, line_ending))),
@@ -209,14 +246,18 @@ fn parse_horizontal_edge<'s>(input: &mut &'s str) -> PResult<&'s str> {
 
 #[cfg(test)]
 mod tests {
+    use winnow::Parser;
+
     #[test]
-    fn example_solvable() {
-        assert_eq!(super::solve(include_str!("EXAMPLE")), 104);
+    fn warehouse_displays_as_parsed() {
+        let map = "########\n#..O.O.#\n##@.O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########";
+        let warehouse = super::parse_warehouse.parse(map).expect("parsable");
+        assert_eq!((warehouse.width, warehouse.height), (6, 6));
+        assert_eq!(warehouse.to_string(), map);
     }
 
-    #[ignore]
     #[test]
-    fn input_solvable() {
-        assert_eq!(super::solve(include_str!("../../inputs/15")), 0);
+    fn example_solvable() {
+        assert_eq!(super::solve(include_str!("EXAMPLE")), 2028);
     }
 }
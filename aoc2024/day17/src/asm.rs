@@ -0,0 +1,321 @@
+//! Programs as source text, one instruction per line:
+//!
+//! ```text
+//! ; comments run from a semicolon to the end of the line
+//! loop:           ; a label for the address of what follows it
+//!     bst A       ; combo operands are 0 to 3, A, B, C or reserved
+//!     bxl 3       ; literal operands are 0 to 7
+//!     bxc         ; the operand bxc ignores is 0 unless given
+//!     out B
+//!     jnz loop    ; jumps take a label or an address
+//!     .raw 5      ; values as they are, like an opcode without operand
+//! ```
+//! Jumps can only reach labels at addresses 0 to 7, as their operand is 3 bits.
+//! [`disassemble`] writes a program back as source that assembles to the same values.
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    error::Error,
+    fmt::{Display, Formatter, Write},
+};
+
+use crate::{
+    disasm::{statement, Instruction, Operand},
+    p1::{OpCode, Program, ThreeBitValue},
+};
+
+/// What is wrong with a line of source.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Problem {
+    UnknownMnemonic(String),
+    /// Not an operand the opcode of the mnemonic takes.
+    Operand(&'static str, String),
+    MissingOperand(&'static str),
+    ExtraOperand(String),
+    /// Not a value of 0 to 7 for `.raw`.
+    Value(String),
+    UnknownLabel(String),
+    DuplicateLabel(String),
+    /// A jump to a label at an address past 7.
+    OutOfReach(String, usize),
+}
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::UnknownMnemonic(mnemonic) => write!(f, "unknown mnemonic '{}'", mnemonic),
+            Problem::Operand(mnemonic, operand) => {
+                write!(f, "'{}' is not an operand of {}", operand, mnemonic)
+            }
+            Problem::MissingOperand(mnemonic) => write!(f, "{} needs an operand", mnemonic),
+            Problem::ExtraOperand(operand) => write!(f, "unexpected '{}'", operand),
+            Problem::Value(value) => write!(f, "'{}' is not a value from 0 to 7", value),
+            Problem::UnknownLabel(label) => write!(f, "no label '{}'", label),
+            Problem::DuplicateLabel(label) => write!(f, "label '{}' is already defined", label),
+            Problem::OutOfReach(label, address) => write!(
+                f,
+                "label '{}' is at {}, past the 7 a jump can reach",
+                label, address
+            ),
+        }
+    }
+}
+
+/// A [`Problem`] on a line of source, counting from 1.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AsmError {
+    pub line: usize,
+    pub problem: Problem,
+}
+impl Display for AsmError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.problem)
+    }
+}
+impl Error for AsmError {}
+
+fn value(s: &str) -> Option<ThreeBitValue> {
+    s.parse::<u8>().ok()?.try_into().ok()
+}
+
+fn is_label(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// The operand of `opcode` written as `operand`, or `Err` with the label it
+/// jumps to if it is one.
+fn operand(
+    opcode: OpCode,
+    operand: Option<&str>,
+) -> Result<Result<ThreeBitValue, String>, Problem> {
+    let mnemonic = opcode.mnemonic();
+    let Some(operand) = operand else {
+        return match opcode {
+            OpCode::Bxc => Ok(Ok(ThreeBitValue::Zero)),
+            _ => Err(Problem::MissingOperand(mnemonic)),
+        };
+    };
+    let invalid = || Problem::Operand(mnemonic, operand.to_string());
+    let kind = Instruction {
+        address: 0,
+        opcode,
+        operand: ThreeBitValue::Zero,
+    }
+    .operand();
+    let written = match (kind, operand) {
+        (Operand::Combo(_), "A") => ThreeBitValue::Four,
+        (Operand::Combo(_), "B") => ThreeBitValue::Five,
+        (Operand::Combo(_), "C") => ThreeBitValue::Six,
+        (Operand::Combo(_), "reserved") => ThreeBitValue::Seven,
+        (Operand::Combo(_), n) => value(n)
+            .filter(|&v| v <= ThreeBitValue::Three)
+            .ok_or_else(invalid)?,
+        (Operand::Literal(_), label) if opcode == OpCode::Jnz && is_label(label) => {
+            return Ok(Err(label.to_string()))
+        }
+        (Operand::Literal(_) | Operand::Ignored(_), n) => value(n).ok_or_else(invalid)?,
+    };
+    Ok(Ok(written))
+}
+
+/// The program written in `source`, see the [module](self) for how.
+pub(crate) fn assemble(source: &str) -> Result<Program, AsmError> {
+    let mut values = Vec::new();
+    let mut labels = HashMap::new();
+    // where labels are jumped to from: the index of the operand, the label and its line
+    let mut jumps = Vec::new();
+    for (i, line) in source.lines().enumerate() {
+        let error = |problem| AsmError {
+            line: i + 1,
+            problem,
+        };
+        let mut code = line.split(';').next().unwrap_or_default().trim();
+        while let Some((label, rest)) = code.split_once(':').filter(|(l, _)| is_label(l.trim())) {
+            let label = label.trim();
+            if labels.insert(label.to_string(), values.len()).is_some() {
+                return Err(error(Problem::DuplicateLabel(label.to_string())));
+            }
+            code = rest.trim();
+        }
+        let mut words = code.split_whitespace();
+        let Some(mnemonic) = words.next() else {
+            continue;
+        };
+        if mnemonic == ".raw" {
+            for word in words
+                .flat_map(|word| word.split(','))
+                .filter(|w| !w.is_empty())
+            {
+                values.push(value(word).ok_or_else(|| error(Problem::Value(word.to_string())))?);
+            }
+            continue;
+        }
+        let opcode = OpCode::from_mnemonic(mnemonic)
+            .ok_or_else(|| error(Problem::UnknownMnemonic(mnemonic.to_string())))?;
+        let written = operand(opcode, words.next()).map_err(error)?;
+        if let Some(extra) = words.next() {
+            return Err(error(Problem::ExtraOperand(extra.to_string())));
+        }
+        values.push(ThreeBitValue::try_from(opcode as u8).expect("opcodes are 3 bits"));
+        match written {
+            Ok(operand) => values.push(operand),
+            Err(label) => {
+                jumps.push((values.len(), label, i + 1));
+                values.push(ThreeBitValue::Zero);
+            }
+        }
+    }
+    for (at, label, line) in jumps {
+        let error = |problem| AsmError { line, problem };
+        let &address = labels
+            .get(&label)
+            .ok_or_else(|| error(Problem::UnknownLabel(label.clone())))?;
+        values[at] = u8::try_from(address)
+            .ok()
+            .and_then(|address| address.try_into().ok())
+            .ok_or_else(|| error(Problem::OutOfReach(label, address)))?;
+    }
+    Ok(Program(values))
+}
+
+/// `program` as source, with what each instruction does as a comment.
+/// Jumps to the start of an instruction (or the end) go to a label `L` and its address.
+pub(crate) fn disassemble(program: &Program) -> String {
+    let instructions = program.instructions();
+    let end = 2 * instructions.len();
+    let labels = instructions
+        .iter()
+        .filter_map(Instruction::target)
+        .filter(|&target| target % 2 == 0 && target <= end)
+        .collect::<BTreeSet<_>>();
+    let mut source = String::new();
+    for instruction in &instructions {
+        if labels.contains(&instruction.address) {
+            writeln!(source, "L{}:", instruction.address).expect("writes to a string");
+        }
+        let code = match instruction.target() {
+            Some(target) if labels.contains(&target) => format!("jnz L{}", target),
+            _ => instruction.to_string(),
+        };
+        writeln!(source, "    {:<12}; {}", code, statement(instruction))
+            .expect("writes to a string");
+    }
+    if labels.contains(&end) {
+        writeln!(source, "L{}:", end).expect("writes to a string");
+    }
+    if let Some(&dangling) = program.0.get(end) {
+        writeln!(
+            source,
+            "    {:<12}; no operand, halting",
+            format!(".raw {}", u8::from(dangling))
+        )
+        .expect("writes to a string");
+    }
+    source
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(program: &Program) -> Vec<u8> {
+        program.0.iter().map(|&v| u8::from(v)).collect()
+    }
+
+    #[test]
+    fn source_is_assembled() {
+        let program = assemble(
+            "; like the inputs
+            start: bst A
+                bxl 3
+                cdv B       ; C = A >> B
+                adv 3
+                bxl 5
+                bxc
+                out B
+                jnz start
+            ",
+        )
+        .expect("assembles");
+        assert_eq!(
+            values(&program),
+            [2, 4, 1, 3, 7, 5, 0, 3, 1, 5, 4, 0, 5, 5, 3, 0]
+        );
+        let program = assemble("jnz end\nout reserved\nend:\nbxc 4\n.raw 5, 6").expect("assembles");
+        assert_eq!(values(&program), [3, 4, 5, 7, 4, 4, 5, 6]);
+    }
+
+    #[test]
+    fn problems_name_their_line() {
+        let problem = |source: &str| assemble(source).err().map(|e| e.to_string());
+        assert_eq!(
+            problem("adv 1\nmul 2"),
+            Some("line 2: unknown mnemonic 'mul'".to_string())
+        );
+        assert_eq!(
+            problem("adv 4"),
+            Some("line 1: '4' is not an operand of adv".to_string())
+        );
+        assert_eq!(
+            problem("bxl A"),
+            Some("line 1: 'A' is not an operand of bxl".to_string())
+        );
+        assert_eq!(
+            problem("\nout"),
+            Some("line 2: out needs an operand".to_string())
+        );
+        assert_eq!(
+            problem("out A B"),
+            Some("line 1: unexpected 'B'".to_string())
+        );
+        assert_eq!(
+            problem(".raw 8"),
+            Some("line 1: '8' is not a value from 0 to 7".to_string())
+        );
+        assert_eq!(
+            problem("jnz nowhere"),
+            Some("line 1: no label 'nowhere'".to_string())
+        );
+        assert_eq!(
+            problem("a:\na: out A"),
+            Some("line 2: label 'a' is already defined".to_string())
+        );
+        assert_eq!(
+            problem("jnz far\nbxc\nbxc\nbxc\nfar: out A"),
+            Some("line 1: label 'far' is at 8, past the 7 a jump can reach".to_string())
+        );
+    }
+
+    #[test]
+    fn disassembly_reassembles() {
+        assert_eq!(
+            disassemble(&assemble("loop: adv 1\nout A\njnz loop").expect("assembles")),
+            "L0:
+    adv 1       ; A >>= 1
+    out A       ; out(A % 8)
+    jnz L0      ; if A != 0 goto 0
+"
+        );
+        // every program of up to 4 values
+        let mut programs = vec![Vec::new()];
+        for len in 1..=4 {
+            let shorter = programs
+                .iter()
+                .filter(|p: &&Vec<ThreeBitValue>| p.len() == len - 1)
+                .cloned()
+                .collect::<Vec<_>>();
+            for program in shorter {
+                for v in 0..8u8 {
+                    let mut longer = program.clone();
+                    longer.push(v.try_into().expect("three bits"));
+                    programs.push(longer);
+                }
+            }
+        }
+        for program in programs.into_iter().map(Program) {
+            let source = disassemble(&program);
+            let reassembled = assemble(&source).unwrap_or_else(|e| panic!("{}\n{}", e, source));
+            assert_eq!(values(&reassembled), values(&program), "{}", source);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Steps through the program of an input, see `day17::debugger`.
+//!
+//! ```text
+//! cargo run -p day17 --bin debugger [PATH]
+//! ```
+//! `PATH` defaults to the input of day 17 in the `inputs` folder.
+//! A `.asm` file is a program written as source instead, see `day17::asm`.
+//! Commands are read from stdin, `help` lists them.
+
+use std::{io, path::Path, process::ExitCode};
+
+use day17::debugger::Debugger;
+use util::DayInput;
+
+fn main() -> ExitCode {
+    let path = std::env::args().nth(1);
+    let input = match DayInput::find(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs"),
+        17,
+        path.as_deref().map(Path::new),
+    ) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("No input to debug: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let debugger = match &path {
+        Some(path) if path.ends_with(".asm") => {
+            Debugger::assembled(input.as_ref()).map_err(|e| e.to_string())
+        }
+        _ => Debugger::new(input.as_ref()).map_err(|e| e.to_string()),
+    };
+    let mut debugger = match debugger {
+        Ok(debugger) => debugger,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    match debugger.repl(io::stdin().lock(), io::stdout()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,546 @@
+//! Stepping through a program instruction by instruction, with commands read
+//! line by line (see [`HELP`]). Every stop shows the state of the computer with
+//! the instruction pointer marked above the program, its output so far and the
+//! values of the watched expressions.
+//! Unlike [`crate::p1::solve`], it only runs with `u64` registers.
+
+use std::{
+    fmt::{Display, Formatter},
+    io::{self, BufRead, Write},
+    num::ParseIntError,
+    str::FromStr,
+};
+
+use util::{ParseError, ParseReport};
+
+use crate::{
+    asm::{assemble, disassemble, AsmError},
+    disasm::Disassembly,
+    p1::{parse_computer, Computer, Fault, OpCode},
+};
+
+/// How many instructions `continue` runs before giving up on the program halting.
+const LIMIT: usize = 1_000_000;
+
+pub const HELP: &str = "\
+step [N]       s  run N instructions (default 1)
+continue       c  run until a breakpoint, a watch changing or halting
+output         o  run until the next output
+break ADDR|OP  b  stop before the instruction at ADDR, or before every OP (like jnz)
+watch EXPR     w  show EXPR at every stop and stop when it changes,
+                  a register with an optional operation: A, B % 8, C >> 3, A & 7, B ^ 5
+delete         d  remove all breakpoints and watches
+set REG VALUE     put VALUE in register A, B or C
+print          p  show the state, breakpoints and watches
+list           l  show the disassembly
+source            show the program as source, which assembles back to it
+restart        r  start over from the initial state
+help           h  show this
+quit           q  stop debugging
+An empty line repeats the last command.
+Registers hold 64 bits, unlike those of the solver, which falls back to arbitrary precision:
+inputs and values that do not fit are rejected, and dividing by 2^64 or more faults.";
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum RegisterName {
+    A,
+    B,
+    C,
+}
+impl FromStr for RegisterName {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" | "a" => Ok(RegisterName::A),
+            "B" | "b" => Ok(RegisterName::B),
+            "C" | "c" => Ok(RegisterName::C),
+            _ => Err(format!("'{}' is not a register, try A, B or C", s)),
+        }
+    }
+}
+impl Display for RegisterName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl Computer {
+    fn register(&self, name: RegisterName) -> u64 {
+        match name {
+            RegisterName::A => self.a.0,
+            RegisterName::B => self.b.0,
+            RegisterName::C => self.c.0,
+        }
+    }
+
+    fn register_mut(&mut self, name: RegisterName) -> &mut u64 {
+        match name {
+            RegisterName::A => &mut self.a.0,
+            RegisterName::B => &mut self.b.0,
+            RegisterName::C => &mut self.c.0,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Operation {
+    Mod,
+    Shr,
+    And,
+    Xor,
+}
+impl Operation {
+    const ALL: [(&'static str, Operation); 4] = [
+        ("%", Operation::Mod),
+        (">>", Operation::Shr),
+        ("&", Operation::And),
+        ("^", Operation::Xor),
+    ];
+
+    fn symbol(self) -> &'static str {
+        Self::ALL
+            .iter()
+            .find(|(_, operation)| *operation == self)
+            .map(|(symbol, _)| *symbol)
+            .expect("every operation has a symbol")
+    }
+}
+
+/// A register, or an operation on it with a number.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Expression {
+    register: RegisterName,
+    operation: Option<(Operation, u64)>,
+}
+impl Expression {
+    /// `None` if the operation cannot be done on the value, like `% 0`.
+    fn eval(&self, computer: &Computer) -> Option<u64> {
+        let value = computer.register(self.register);
+        match self.operation {
+            None => Some(value),
+            Some((Operation::Mod, n)) => value.checked_rem(n),
+            Some((Operation::Shr, n)) => Some(value.checked_shr(n.try_into().ok()?).unwrap_or(0)),
+            Some((Operation::And, n)) => Some(value & n),
+            Some((Operation::Xor, n)) => Some(value ^ n),
+        }
+    }
+}
+impl FromStr for Expression {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let split = s.find(|c: char| !c.is_alphanumeric()).unwrap_or(s.len());
+        let (register, rest) = s.split_at(split);
+        let register = register.parse()?;
+        let rest = rest.trim();
+        if rest.is_empty() {
+            return Ok(Expression {
+                register,
+                operation: None,
+            });
+        }
+        let (operation, number) = Operation::ALL
+            .iter()
+            .find_map(|&(symbol, operation)| Some((operation, rest.strip_prefix(symbol)?)))
+            .ok_or(format!("'{}' is not an operation, try %, >>, & or ^", rest))?;
+        let number = number
+            .trim()
+            .parse()
+            .map_err(|e| format!("'{}' is not a number: {}", number.trim(), e))?;
+        Ok(Expression {
+            register,
+            operation: Some((operation, number)),
+        })
+    }
+}
+impl Display for Expression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.operation {
+            None => write!(f, "{}", self.register),
+            Some((operation, n)) => write!(f, "{} {} {}", self.register, operation.symbol(), n),
+        }
+    }
+}
+
+struct Watch {
+    expression: Expression,
+    /// As of the last stop.
+    value: Option<u64>,
+}
+impl Display for Watch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.value {
+            Some(value) => write!(f, "{} = {}", self.expression, value),
+            None => write!(f, "{} = undefined", self.expression),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Breakpoint {
+    Address(usize),
+    OpCode(OpCode),
+}
+impl Breakpoint {
+    fn hit(&self, computer: &Computer) -> bool {
+        match *self {
+            Breakpoint::Address(address) => computer.ins_ptr.0 == address,
+            Breakpoint::OpCode(opcode) => {
+                computer.next_instruction().map(|(next, _)| next) == Some(opcode)
+            }
+        }
+    }
+}
+impl FromStr for Breakpoint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(address) = s.parse() {
+            return Ok(Breakpoint::Address(address));
+        }
+        OpCode::from_mnemonic(s)
+            .map(Breakpoint::OpCode)
+            .ok_or(format!("'{}' is neither an address nor an opcode", s))
+    }
+}
+impl Display for Breakpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Breakpoint::Address(address) => write!(f, "at {}", address),
+            Breakpoint::OpCode(opcode) => write!(f, "on {}", opcode.mnemonic()),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Command {
+    Step(usize),
+    Continue,
+    Output,
+    Break(Breakpoint),
+    Watch(Expression),
+    Delete,
+    Set(RegisterName, u64),
+    Print,
+    List,
+    Source,
+    Restart,
+    Help,
+    Quit,
+}
+impl FromStr for Command {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, rest) = s
+            .trim()
+            .split_once(char::is_whitespace)
+            .unwrap_or((s.trim(), ""));
+        let rest = rest.trim();
+        fn number<T: FromStr<Err = ParseIntError>>(s: &str) -> Result<T, String> {
+            s.parse()
+                .map_err(|e| format!("'{}' is not a number: {}", s, e))
+        }
+        let command = match name {
+            "step" | "s" if rest.is_empty() => Command::Step(1),
+            "step" | "s" => match number(rest)? {
+                0 => return Err("step needs at least 1 instruction to run".to_string()),
+                n => Command::Step(n),
+            },
+            "continue" | "c" => Command::Continue,
+            "output" | "o" => Command::Output,
+            "break" | "b" => Command::Break(rest.parse()?),
+            "watch" | "w" => Command::Watch(rest.parse()?),
+            "delete" | "d" => Command::Delete,
+            "set" => {
+                let (register, value) = rest
+                    .split_once(char::is_whitespace)
+                    .ok_or("set needs a register and a value")?;
+                Command::Set(register.parse()?, number(value.trim())?)
+            }
+            "print" | "p" => Command::Print,
+            "list" | "l" => Command::List,
+            "source" => Command::Source,
+            "restart" | "r" => Command::Restart,
+            "help" | "h" => Command::Help,
+            "quit" | "q" => Command::Quit,
+            _ => return Err(format!("unknown command '{}', try help", name)),
+        };
+        Ok(command)
+    }
+}
+
+/// Why running stopped.
+enum Stop {
+    Stepped,
+    Halted,
+    Breakpoint(Breakpoint),
+    /// The index of the watch that changed, and its value before.
+    Watch(usize, Option<u64>),
+    Output,
+    Limit,
+    Fault(Fault),
+}
+
+/// How far to run.
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Until {
+    Steps(usize),
+    Stop,
+    Output,
+}
+
+pub struct Debugger {
+    initial: Computer,
+    computer: Computer,
+    breakpoints: Vec<Breakpoint>,
+    watches: Vec<Watch>,
+}
+impl Debugger {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Debugger::of(parse_computer.parse_report(input)?))
+    }
+
+    /// Debugging a program written as source (see [`crate::asm`]),
+    /// starting with all registers at 0.
+    pub fn assembled(source: &str) -> Result<Self, AsmError> {
+        Ok(Debugger::of(Computer {
+            program: assemble(source)?,
+            ..Default::default()
+        }))
+    }
+
+    fn of(initial: Computer) -> Self {
+        Debugger {
+            computer: initial.with_a(initial.a.0),
+            initial,
+            breakpoints: Vec::new(),
+            watches: Vec::new(),
+        }
+    }
+
+    /// Runs the commands from `commands`, until they run out or one quits.
+    /// Prompts for and answers commands on `out`.
+    pub fn repl(&mut self, commands: impl BufRead, mut out: impl Write) -> io::Result<()> {
+        writeln!(out, "{}\n", self.computer)?;
+        let mut last = None;
+        let mut lines = commands.lines();
+        loop {
+            write!(out, "(17) ")?;
+            out.flush()?;
+            let Some(line) = lines.next().transpose()? else {
+                return writeln!(out);
+            };
+            let command = match (line.trim(), last) {
+                ("", Some(last)) => last,
+                ("", None) => continue,
+                (line, _) => match line.parse() {
+                    Ok(command) => command,
+                    Err(e) => {
+                        writeln!(out, "{}", e)?;
+                        continue;
+                    }
+                },
+            };
+            if command == Command::Quit {
+                return Ok(());
+            }
+            self.execute(command, &mut out)?;
+            last = Some(command);
+        }
+    }
+
+    fn execute(&mut self, command: Command, out: &mut impl Write) -> io::Result<()> {
+        match command {
+            Command::Step(n) => self.run(Until::Steps(n), out),
+            Command::Continue => self.run(Until::Stop, out),
+            Command::Output => self.run(Until::Output, out),
+            Command::Break(breakpoint) => {
+                self.breakpoints.push(breakpoint);
+                writeln!(out, "breakpoint {}", breakpoint)
+            }
+            Command::Watch(expression) => {
+                let watch = Watch {
+                    expression,
+                    value: expression.eval(&self.computer),
+                };
+                writeln!(out, "watching {}", watch)?;
+                self.watches.push(watch);
+                Ok(())
+            }
+            Command::Delete => {
+                self.breakpoints.clear();
+                self.watches.clear();
+                writeln!(out, "removed all breakpoints and watches")
+            }
+            Command::Set(register, value) => {
+                *self.computer.register_mut(register) = value;
+                // an edit is not a change to stop for later
+                self.refresh_watches();
+                self.show(out)
+            }
+            Command::Print => {
+                self.show(out)?;
+                for breakpoint in &self.breakpoints {
+                    writeln!(out, "breakpoint {}", breakpoint)?;
+                }
+                Ok(())
+            }
+            Command::List => write!(out, "{}", Disassembly::of(&self.computer.program)),
+            Command::Source => write!(out, "{}", disassemble(&self.computer.program)),
+            Command::Restart => {
+                self.computer = self.initial.with_a(self.initial.a.0);
+                self.refresh_watches();
+                self.show(out)
+            }
+            Command::Help => writeln!(out, "{}", HELP),
+            Command::Quit => Ok(()),
+        }
+    }
+
+    fn run(&mut self, until: Until, out: &mut impl Write) -> io::Result<()> {
+        let stop = self.run_until(until);
+        match stop {
+            Stop::Stepped => {}
+            Stop::Halted => writeln!(out, "halted")?,
+            Stop::Breakpoint(breakpoint) => writeln!(out, "stopped at breakpoint {}", breakpoint)?,
+            Stop::Watch(i, before) => {
+                let before = before.map_or_else(|| "undefined".to_string(), |v| v.to_string());
+                writeln!(out, "{} changed from {}", self.watches[i], before)?;
+            }
+            Stop::Output => {}
+            Stop::Limit => writeln!(out, "still running after {} instructions", LIMIT)?,
+            Stop::Fault(fault) => writeln!(out, "fault: {}", fault)?,
+        }
+        self.show(out)
+    }
+
+    fn run_until(&mut self, until: Until) -> Stop {
+        let outputs = self.computer.output.0.len();
+        for ran in 0.. {
+            // a breakpoint where running starts was already stopped at
+            if ran > 0 {
+                if let Some(&breakpoint) = self.breakpoints.iter().find(|b| b.hit(&self.computer)) {
+                    return Stop::Breakpoint(breakpoint);
+                }
+            }
+            match self.computer.step() {
+                Ok(Some(_)) => {}
+                Ok(None) => return Stop::Halted,
+                Err(fault) => return Stop::Fault(fault),
+            }
+            let changed = self
+                .watches
+                .iter()
+                .position(|watch| watch.expression.eval(&self.computer) != watch.value);
+            if let Some(i) = changed {
+                let before = self.watches[i].value;
+                self.refresh_watches();
+                return Stop::Watch(i, before);
+            }
+            if until == Until::Output && self.computer.output.0.len() > outputs {
+                return Stop::Output;
+            }
+            if until == Until::Steps(ran + 1) {
+                return Stop::Stepped;
+            }
+            if ran + 1 == LIMIT {
+                return Stop::Limit;
+            }
+        }
+        unreachable!("runs until it stops")
+    }
+
+    fn refresh_watches(&mut self) {
+        for watch in &mut self.watches {
+            watch.value = watch.expression.eval(&self.computer);
+        }
+    }
+
+    fn show(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(out, "{}", self.computer)?;
+        writeln!(out, "Output: {}", self.computer.output)?;
+        for watch in &self.watches {
+            writeln!(out, "{}", watch)?;
+        }
+        writeln!(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The output of debugging `input` with `commands`, one per line.
+    fn session(input: &str, commands: &str) -> String {
+        let mut out = Vec::new();
+        Debugger::new(input)
+            .expect("parsable")
+            .repl(commands.as_bytes(), &mut out)
+            .expect("writes to a vec");
+        String::from_utf8(out).expect("utf-8")
+    }
+
+    #[test]
+    fn commands_are_parsed() {
+        assert_eq!("s".parse(), Ok(Command::Step(1)));
+        assert_eq!("step 12".parse(), Ok(Command::Step(12)));
+        assert!("step 0".parse::<Command>().is_err());
+        assert_eq!(
+            "b jnz".parse(),
+            Ok(Command::Break(Breakpoint::OpCode(OpCode::Jnz)))
+        );
+        assert_eq!(
+            "break 4".parse(),
+            Ok(Command::Break(Breakpoint::Address(4)))
+        );
+        assert_eq!(
+            "watch A>>3".parse(),
+            Ok(Command::Watch(Expression {
+                register: RegisterName::A,
+                operation: Some((Operation::Shr, 3)),
+            }))
+        );
+        assert_eq!("set b 7".parse(), Ok(Command::Set(RegisterName::B, 7)));
+        assert!("break xyz".parse::<Command>().is_err());
+        assert!("watch D".parse::<Command>().is_err());
+        assert!("jump 2".parse::<Command>().is_err());
+    }
+
+    #[test]
+    fn stepping_shows_the_instruction_pointer() {
+        let out = session(include_str!("EXAMPLE"), "s\n\nq\n");
+        assert!(out.contains("         v-- IPTR=0\n"), "{}", out);
+        assert!(out.contains("             v-- IPTR=2\n"), "{}", out);
+        // the empty line repeated the step
+        assert!(
+            out.contains("                 v-- IPTR=4\nProgram: 0,1,5,4,3,0\nRegister A: 364\n"),
+            "{}",
+            out
+        );
+    }
+
+    #[test]
+    fn running_stops_where_asked() {
+        // A = 729, outputs A % 8 after each shift: 4,6,3,5,6,3,5,2,1,0
+        let out = session(include_str!("EXAMPLE"), "o\no\nb jnz\nc\nd\nc\n");
+        assert!(out.contains("Output: 4\n"), "{}", out);
+        assert!(out.contains("Output: 4,6\n"), "{}", out);
+        assert!(out.contains("stopped at breakpoint on jnz\n"), "{}", out);
+        assert!(out.contains("halted\n"), "{}", out);
+        assert!(out.contains("Output: 4,6,3,5,6,3,5,2,1,0\n"), "{}", out);
+    }
+
+    #[test]
+    fn watches_stop_on_changes() {
+        let out = session(include_str!("EXAMPLE"), "watch A % 8\nset A 8\nc\nc\n");
+        assert!(out.contains("watching A % 8 = 1\n"), "{}", out);
+        // setting A is not a change to stop at
+        assert!(out.contains("Register A: 8\n"), "{}", out);
+        assert!(out.contains("A % 8 = 0\n\n"), "{}", out);
+        // 8 >> 1 = 4
+        assert!(out.contains("A % 8 = 4 changed from 0\n"), "{}", out);
+        assert!(out.contains("A % 8 = 2 changed from 4\n"), "{}", out);
+    }
+}
@@ -0,0 +1,332 @@
+//! Readable views of a program: a listing of its instructions with their
+//! operands resolved, and pseudocode lifted from that listing.
+//! Part 2 is worked out from what the pseudocode of the input does.
+
+use std::{
+    collections::BTreeSet,
+    fmt::{Display, Formatter},
+};
+
+use crate::p1::{ComboOperand, OpCode, Program, ThreeBitValue};
+
+/// An opcode and its operand, at an even address of a program.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct Instruction {
+    pub(crate) address: usize,
+    pub(crate) opcode: OpCode,
+    pub(crate) operand: ThreeBitValue,
+}
+impl OpCode {
+    pub(crate) const ALL: [OpCode; 8] = [
+        OpCode::Adv,
+        OpCode::Bxl,
+        OpCode::Bst,
+        OpCode::Jnz,
+        OpCode::Bxc,
+        OpCode::Out,
+        OpCode::Bdv,
+        OpCode::Cdv,
+    ];
+
+    pub(crate) fn mnemonic(self) -> &'static str {
+        match self {
+            OpCode::Adv => "adv",
+            OpCode::Bxl => "bxl",
+            OpCode::Bst => "bst",
+            OpCode::Jnz => "jnz",
+            OpCode::Bxc => "bxc",
+            OpCode::Out => "out",
+            OpCode::Bdv => "bdv",
+            OpCode::Cdv => "cdv",
+        }
+    }
+
+    /// The opcode written as `mnemonic`, like `jnz`.
+    pub(crate) fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|opcode| opcode.mnemonic() == mnemonic)
+    }
+}
+
+impl Instruction {
+    pub(crate) fn mnemonic(&self) -> &'static str {
+        self.opcode.mnemonic()
+    }
+
+    /// The operand as the opcode reads it: literal, combo or not at all.
+    pub(crate) fn operand(&self) -> Operand {
+        match self.opcode {
+            OpCode::Bxl | OpCode::Jnz => Operand::Literal(self.operand),
+            OpCode::Bxc => Operand::Ignored(self.operand),
+            OpCode::Adv | OpCode::Bst | OpCode::Out | OpCode::Bdv | OpCode::Cdv => {
+                Operand::Combo(self.operand)
+            }
+        }
+    }
+
+    /// Where it jumps to, if it is a jump.
+    pub(crate) fn target(&self) -> Option<usize> {
+        (self.opcode == OpCode::Jnz).then_some(u8::from(self.operand) as usize)
+    }
+}
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.operand() {
+            Operand::Ignored(ThreeBitValue::Zero) => write!(f, "{}", self.mnemonic()),
+            operand => write!(f, "{} {}", self.mnemonic(), operand),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Operand {
+    Literal(ThreeBitValue),
+    Combo(ThreeBitValue),
+    /// Read but not used, as by `bxc`.
+    Ignored(ThreeBitValue),
+}
+impl Display for Operand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Operand::Literal(value) | Operand::Ignored(value) => write!(f, "{}", u8::from(value)),
+            Operand::Combo(value) => match ComboOperand::from(value) {
+                ComboOperand::Literal(n) => write!(f, "{}", u8::from(n)),
+                ComboOperand::RegA => write!(f, "A"),
+                ComboOperand::RegB => write!(f, "B"),
+                ComboOperand::RegC => write!(f, "C"),
+                ComboOperand::Reserved => write!(f, "reserved"),
+            },
+        }
+    }
+}
+
+impl Program {
+    /// Every instruction at an even address. A last opcode without operand is left out.
+    pub(crate) fn instructions(&self) -> Vec<Instruction> {
+        self.0
+            .chunks_exact(2)
+            .enumerate()
+            .map(|(i, pair)| Instruction {
+                address: 2 * i,
+                opcode: OpCode::from(pair[0]),
+                operand: pair[1],
+            })
+            .collect()
+    }
+}
+
+/// What `instruction` does, as a statement of pseudocode.
+pub(crate) fn statement(instruction: &Instruction) -> String {
+    let operand = instruction.operand();
+    // combo operands are only ever read mod 8 if they are registers
+    let mod_8 = |operand: Operand| match operand {
+        Operand::Combo(value) if u8::from(value) < 4 => operand.to_string(),
+        _ => format!("{} % 8", operand),
+    };
+    match instruction.opcode {
+        OpCode::Adv => format!("A >>= {}", operand),
+        OpCode::Bdv => format!("B = A >> {}", operand),
+        OpCode::Cdv => format!("C = A >> {}", operand),
+        OpCode::Bxl => format!("B ^= {}", operand),
+        OpCode::Bxc => "B ^= C".to_string(),
+        OpCode::Bst => format!("B = {}", mod_8(operand)),
+        OpCode::Out => format!("out({})", mod_8(operand)),
+        OpCode::Jnz => format!("if A != 0 goto {}", operand),
+    }
+}
+
+/// Why a jump to `target` in a program of `end` values does not land on an
+/// instruction, if it does not.
+fn unlabeled_target(target: usize, end: usize) -> Option<&'static str> {
+    if target >= end {
+        Some("past the end, halting")
+    } else if target % 2 == 1 {
+        Some("reading operands as opcodes")
+    } else {
+        None
+    }
+}
+
+/// The instructions of a program, one per line with its address and what it
+/// does, marking the addresses jumps go to with `>`.
+pub struct Disassembly {
+    instructions: Vec<Instruction>,
+    /// An opcode at the end without an operand, where the program halts.
+    dangling: Option<ThreeBitValue>,
+}
+impl Disassembly {
+    pub(crate) fn of(program: &Program) -> Self {
+        Disassembly {
+            instructions: program.instructions(),
+            dangling: (program.0.len() % 2 == 1).then(|| program.0[program.0.len() - 1]),
+        }
+    }
+}
+impl Display for Disassembly {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let targets = self
+            .instructions
+            .iter()
+            .filter_map(Instruction::target)
+            .collect::<BTreeSet<_>>();
+        let end = 2 * self.instructions.len();
+        for instruction in &self.instructions {
+            let marker = if targets.contains(&instruction.address) {
+                '>'
+            } else {
+                ' '
+            };
+            write!(
+                f,
+                "{}{:>3}: {:<12}; {}",
+                marker,
+                instruction.address,
+                instruction.to_string(),
+                statement(instruction)
+            )?;
+            if let Some(note) = instruction
+                .target()
+                .and_then(|target| unlabeled_target(target, end))
+            {
+                write!(f, " ({})", note)?;
+            }
+            writeln!(f)?;
+        }
+        if let Some(opcode) = self.dangling {
+            writeln!(
+                f,
+                " {:>3}: {:<12}; no operand, halting",
+                end,
+                OpCode::from(opcode).mnemonic()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The statements of a program as pseudocode. A program that only jumps back
+/// to its start from its end is a `do { ... } while A != 0` loop, as the
+/// programs of this puzzle are; others keep their jumps as `goto`s to labels.
+pub struct Pseudocode(Vec<Instruction>);
+impl Pseudocode {
+    pub(crate) fn of(program: &Program) -> Self {
+        Pseudocode(program.instructions())
+    }
+
+    /// The body of the loop the whole program is, if it is one.
+    pub(crate) fn loop_body(&self) -> Option<&[Instruction]> {
+        let (last, body) = self.0.split_last()?;
+        let only_jump = last.target() == Some(0) && body.iter().all(|i| i.target().is_none());
+        only_jump.then_some(body)
+    }
+}
+impl Display for Pseudocode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(body) = self.loop_body() {
+            writeln!(f, "do {{")?;
+            for instruction in body {
+                writeln!(f, "    {};", statement(instruction))?;
+            }
+            return writeln!(f, "}} while A != 0;");
+        }
+        let targets = self
+            .0
+            .iter()
+            .filter_map(Instruction::target)
+            .collect::<BTreeSet<_>>();
+        let end = 2 * self.0.len();
+        for instruction in &self.0 {
+            if targets.contains(&instruction.address) {
+                writeln!(f, "L{}:", instruction.address)?;
+            }
+            match instruction.target() {
+                Some(target) => match unlabeled_target(target, end) {
+                    Some(note) => writeln!(f, "    if A != 0 goto L{}; ({})", target, note)?,
+                    None => writeln!(f, "    if A != 0 goto L{};", target)?,
+                },
+                None => writeln!(f, "    {};", statement(instruction))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::assemble;
+
+    /// A program like the inputs: a single loop shifting 3 bits out of `A` per output.
+    const INPUT_LIKE: &str = "
+        loop: bst A
+            bxl 3
+            cdv B
+            adv 3
+            bxl 5
+            bxc 4
+            out B
+            jnz loop
+    ";
+
+    fn program(source: &str) -> Program {
+        assemble(source).expect("assembles")
+    }
+
+    #[test]
+    fn instructions_are_listed() {
+        assert_eq!(
+            Disassembly::of(&program(INPUT_LIKE)).to_string(),
+            ">  0: bst A       ; B = A % 8
+   2: bxl 3       ; B ^= 3
+   4: cdv B       ; C = A >> B
+   6: adv 3       ; A >>= 3
+   8: bxl 5       ; B ^= 5
+  10: bxc 4       ; B ^= C
+  12: out B       ; out(B % 8)
+  14: jnz 0       ; if A != 0 goto 0
+"
+        );
+        assert_eq!(
+            Disassembly::of(&program("jnz 1\nbxc\n.raw 5")).to_string(),
+            "   0: jnz 1       ; if A != 0 goto 1 (reading operands as opcodes)
+   2: bxc         ; B ^= C
+   4: out         ; no operand, halting
+"
+        );
+    }
+
+    #[test]
+    fn loops_are_lifted() {
+        assert_eq!(
+            Pseudocode::of(&program(INPUT_LIKE)).to_string(),
+            "do {
+    B = A % 8;
+    B ^= 3;
+    C = A >> B;
+    A >>= 3;
+    B ^= 5;
+    B ^= C;
+    out(B % 8);
+} while A != 0;
+"
+        );
+        assert_eq!(
+            Pseudocode::of(&program("adv 1\nL2: jnz L6\nout A\nL6: jnz L2")).to_string(),
+            "    A >>= 1;
+L2:
+    if A != 0 goto L6;
+    out(A % 8);
+L6:
+    if A != 0 goto L2;
+"
+        );
+        assert_eq!(
+            Pseudocode::of(&program("jnz 1\nbxc\njnz 6")).to_string(),
+            "    if A != 0 goto L1; (reading operands as opcodes)
+    B ^= C;
+    if A != 0 goto L6; (past the end, halting)
+"
+        );
+    }
+}
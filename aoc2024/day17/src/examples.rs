@@ -0,0 +1,13 @@
+//! Generated by `aoc ingest` from the puzzle descriptions.
+//! Ingesting again overwrites this file.
+
+#[test]
+fn p1_example_solvable() {
+    let mut registry = util::Registry::new();
+    crate::register(&mut registry);
+    let answer = registry.answer(17, 1, include_str!("P1_EXAMPLE"));
+    assert_eq!(
+        answer.expect("part is registered").unwrap(),
+        "4,6,3,5,6,3,5,2,1,0"
+    );
+}
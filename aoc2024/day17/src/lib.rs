@@ -0,0 +1,13 @@
+pub mod asm;
+pub mod debugger;
+pub mod disasm;
+#[cfg(test)]
+mod examples;
+pub mod p1;
+pub mod p2;
+mod word;
+
+pub fn register(registry: &mut util::Registry) {
+    registry.register(17, 1, |input| p1::solve(input));
+    registry.register(17, 2, |input| p2::solve(input));
+}
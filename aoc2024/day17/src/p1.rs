@@ -1,9 +1,14 @@
 #![doc = include_str!("../p1.md")]
 
 use itertools::Itertools;
+use num_bigint::BigUint;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
+use std::error::Error;
 use std::fmt::{Display, Formatter};
-use std::ops::BitXorAssign;
+use util::ParseReport;
+
+use crate::disasm::{Disassembly, Pseudocode};
+use crate::word::Word;
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -17,7 +22,7 @@ use winnow::{
 
 #[derive(Debug, IntoPrimitive, TryFromPrimitive, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 #[repr(u8)]
-enum ThreeBitValue {
+pub(crate) enum ThreeBitValue {
     Zero = 0,
     One = 1,
     Two = 2,
@@ -29,22 +34,17 @@ enum ThreeBitValue {
 }
 #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 struct LiteralOperand(ThreeBitValue);
-impl From<LiteralOperand> for ArbitraryUInt {
-    fn from(value: LiteralOperand) -> Self {
-        u8::from(value.0).into()
-    }
-}
 
 #[derive(IntoPrimitive, TryFromPrimitive, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 #[repr(u8)]
-enum TwoBitValue {
+pub(crate) enum TwoBitValue {
     Zero = 0,
     One = 1,
     Two = 2,
     Three = 3,
 }
 #[derive(Copy, Clone, Eq, PartialEq)]
-enum ComboOperand {
+pub(crate) enum ComboOperand {
     /// 0..=3: Literal values
     Literal(TwoBitValue),
     /// 4: Value of register `A`
@@ -53,7 +53,7 @@ enum ComboOperand {
     RegB,
     /// 6: Value of register `C`
     RegC,
-    /// 7: "reserved and does not appear in valid programs" (can be ignored?)
+    /// 7: "reserved and does not appear in valid programs", so it faults
     Reserved,
 }
 impl From<ThreeBitValue> for ComboOperand {
@@ -82,7 +82,7 @@ impl From<ThreeBitValue> for ComboOperand {
 /// In order, 0..=7.
 #[repr(u8)]
 #[derive(Debug, TryFromPrimitive, Copy, Clone, Eq, PartialEq)]
-enum OpCode {
+pub(crate) enum OpCode {
     /// division with register `A` and a combo operand `O` : `A / 2.pow(O) -> A`
     Adv = 0,
     /// bitwise XOR of `B` and a literal operand `L`: `B^L -> B`
@@ -109,38 +109,66 @@ impl From<ThreeBitValue> for OpCode {
             .expect("same variant count")
     }
 }
-type ArbitraryUInt = usize;
-#[derive(Default)]
-struct Register(ArbitraryUInt);
-#[derive(Default)]
-struct Program(Vec<ThreeBitValue>);
+#[derive(Default, Clone)]
+pub(crate) struct Register<R>(pub(crate) R);
+#[derive(Default, Clone)]
+pub(crate) struct Program(pub(crate) Vec<ThreeBitValue>);
 #[derive(Default)]
-struct ProgramOutput(Vec<ThreeBitValue>);
+pub struct ProgramOutput(pub(crate) Vec<ThreeBitValue>);
 impl Display for ProgramOutput {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0.iter().map(|&v| u8::from(v)).join(","))
     }
 }
 #[derive(Default)]
-struct InstructionPtr(ArbitraryUInt);
+pub(crate) struct InstructionPtr(pub(crate) usize);
 impl InstructionPtr {
     fn increment(&mut self) {
         self.0 += 2;
     }
     fn set(&mut self, literal: LiteralOperand) {
-        self.0 = literal.0 as ArbitraryUInt
+        self.0 = literal.0 as usize
     }
 }
+
+/// Why a program stopped other than by halting.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Fault {
+    /// The combo operand 7 of the instruction at this address,
+    /// which "will not appear in valid programs".
+    ReservedOperand(usize),
+    /// Dividing by `2^power` at this address, which does not fit the registers.
+    Overflow { address: usize, power: String },
+}
+impl Display for Fault {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Fault::ReservedOperand(address) => {
+                write!(f, "reserved combo operand at {}", address)
+            }
+            Fault::Overflow { address, power } => {
+                write!(
+                    f,
+                    "dividing by 2^{} at {} overflows the registers",
+                    power, address
+                )
+            }
+        }
+    }
+}
+impl Error for Fault {}
+
+/// A computer with registers of `R`, see [`Word`] for which to use.
 #[derive(Default)]
-struct Computer {
-    a: Register,
-    b: Register,
-    c: Register,
-    program: Program,
-    ins_ptr: InstructionPtr,
-    output: ProgramOutput,
+pub(crate) struct Computer<R = u64> {
+    pub(crate) a: Register<R>,
+    pub(crate) b: Register<R>,
+    pub(crate) c: Register<R>,
+    pub(crate) program: Program,
+    pub(crate) ins_ptr: InstructionPtr,
+    pub(crate) output: ProgramOutput,
 }
-impl Display for Computer {
+impl<R: Word> Display for Computer<R> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         // print instruction pointer above program
         writeln!(
@@ -161,65 +189,92 @@ impl Display for Computer {
         write!(f, "Register C: {}", self.c.0)
     }
 }
-impl Computer {
-    fn calc_combo(&self, operand: ThreeBitValue) -> ArbitraryUInt {
-        match ComboOperand::from(operand) {
-            ComboOperand::Literal(n) => u8::from(n) as ArbitraryUInt,
-            ComboOperand::RegA => self.a.0,
-            ComboOperand::RegB => self.b.0,
-            ComboOperand::RegC => self.c.0,
-            ComboOperand::Reserved => unreachable!("is not supposed to appear for valid programs"),
+impl<R: Word> Computer<R> {
+    /// A fresh start of the same program and `B` and `C`, with `a` in register `A`.
+    pub(crate) fn with_a(&self, a: R) -> Self {
+        Computer {
+            a: Register(a),
+            b: self.b.clone(),
+            c: self.c.clone(),
+            program: self.program.clone(),
+            ..Default::default()
         }
     }
-    fn run(mut self) -> Self {
-        eprintln!("Starting program with state:\n{}\n", self);
-        while let Some((opcode, operand_value)) = self.next_instruction() {
-            // not in order, but grouped in relation
-            match opcode {
-                // conditional jump
-                OpCode::Jnz if self.a.0 != 0 => self.ins_ptr.set(LiteralOperand(operand_value)),
-                // all other branches
-                non_jmp @ (OpCode::Jnz
-                | OpCode::Adv
-                | OpCode::Bdv
-                | OpCode::Cdv
-                | OpCode::Bxl
-                | OpCode::Bxc
-                | OpCode::Bst
-                | OpCode::Out) => {
-                    self.ins_ptr.increment();
-                    match non_jmp {
-                        OpCode::Jnz => { /* do nothing */ }
-                        // division
-                        OpCode::Adv => {
-                            self.a.0 /= 2usize.pow(self.calc_combo(operand_value) as u32)
-                        }
-                        OpCode::Bdv => {
-                            self.b.0 = self.a.0 / 2usize.pow(self.calc_combo(operand_value) as u32)
-                        }
-                        OpCode::Cdv => {
-                            self.c.0 = self.a.0 / 2usize.pow(self.calc_combo(operand_value) as u32)
-                        }
-                        // bitwise XOR
-                        OpCode::Bxl => self.b.0 ^= usize::from(LiteralOperand(operand_value)),
-                        OpCode::Bxc => self.b.0.bitxor_assign(self.c.0),
-                        // modulo 8
-                        OpCode::Bst => self.b.0 = self.calc_combo(operand_value) % 8,
-                        OpCode::Out => self.output.0.push(
-                            u8::try_from(self.calc_combo(operand_value) % 8usize)
-                                .ok()
-                                .and_then(|v| v.try_into().ok())
-                                .expect("v % 8 < 8"),
-                        ),
-                    };
-                }
+
+    fn calc_combo(&self, operand: ThreeBitValue) -> Result<R, Fault> {
+        Ok(match ComboOperand::from(operand) {
+            ComboOperand::Literal(n) => R::from_value(n.into()),
+            ComboOperand::RegA => self.a.0.clone(),
+            ComboOperand::RegB => self.b.0.clone(),
+            ComboOperand::RegC => self.c.0.clone(),
+            ComboOperand::Reserved => return Err(Fault::ReservedOperand(self.ins_ptr.0)),
+        })
+    }
+
+    /// `A / 2.pow(O)`, for all three division opcodes.
+    fn divide(&self, operand: ThreeBitValue) -> Result<R, Fault> {
+        let power = self.calc_combo(operand)?;
+        self.a.0.div_pow2(&power).ok_or_else(|| Fault::Overflow {
+            address: self.ins_ptr.0,
+            power: power.to_string(),
+        })
+    }
+
+    pub(crate) fn run(mut self) -> Result<Self, Fault> {
+        log::debug!("Starting program with state:\n{}", self);
+        while self.step()?.is_some() {
+            log::trace!("{}", self);
+        }
+        Ok(self)
+    }
+
+    /// Runs the instruction at the instruction pointer, giving it back,
+    /// or `None` if the program has halted.
+    /// The instruction pointer stays at an instruction that faults.
+    pub(crate) fn step(&mut self) -> Result<Option<(OpCode, ThreeBitValue)>, Fault> {
+        let Some((opcode, operand_value)) = self.next_instruction() else {
+            return Ok(None);
+        };
+        // not in order, but grouped in relation
+        match opcode {
+            // conditional jump
+            OpCode::Jnz if !self.a.0.is_zero() => self.ins_ptr.set(LiteralOperand(operand_value)),
+            // all other branches
+            non_jmp @ (OpCode::Jnz
+            | OpCode::Adv
+            | OpCode::Bdv
+            | OpCode::Cdv
+            | OpCode::Bxl
+            | OpCode::Bxc
+            | OpCode::Bst
+            | OpCode::Out) => {
+                match non_jmp {
+                    OpCode::Jnz => { /* do nothing */ }
+                    // division
+                    OpCode::Adv => self.a.0 = self.divide(operand_value)?,
+                    OpCode::Bdv => self.b.0 = self.divide(operand_value)?,
+                    OpCode::Cdv => self.c.0 = self.divide(operand_value)?,
+                    // bitwise XOR
+                    OpCode::Bxl => self.b.0 = self.b.0.xor(&R::from_value(operand_value.into())),
+                    OpCode::Bxc => self.b.0 = self.b.0.xor(&self.c.0),
+                    // modulo 8
+                    OpCode::Bst => {
+                        let value = self.calc_combo(operand_value)?.lowest_three_bits();
+                        self.b.0 = R::from_value(value.into())
+                    }
+                    OpCode::Out => {
+                        let value = self.calc_combo(operand_value)?.lowest_three_bits();
+                        self.output.0.push(value)
+                    }
+                };
+                self.ins_ptr.increment();
             }
-            eprintln!("{}", self);
         }
-        self
+        Ok(Some((opcode, operand_value)))
     }
 
-    fn next_instruction(&self) -> Option<(OpCode, ThreeBitValue)> {
+    /// The instruction at the instruction pointer, unless the program has halted there.
+    pub(crate) fn next_instruction(&self) -> Option<(OpCode, ThreeBitValue)> {
         self.program.0.get(self.ins_ptr.0).and_then(|&x| {
             self.program
                 .0
@@ -229,11 +284,7 @@ impl Computer {
     }
 }
 
-fn main() {
-    util::DayInput::find::<17>().solve_with(solve);
-}
-
-fn parse_computer(input: &mut &str) -> PResult<Computer> {
+pub(crate) fn parse_computer<R: Word>(input: &mut &str) -> PResult<Computer<R>> {
     separated_pair(
         (
             terminated(parse_register, line_ending),
@@ -253,12 +304,8 @@ fn parse_computer(input: &mut &str) -> PResult<Computer> {
     })
     .parse_next(input)
 }
-fn parse_register(input: &mut &str) -> PResult<Register> {
-    preceded(
-        ("Register ", alpha1, ": "),
-        dec_uint.map(|n: ArbitraryUInt| Register(n)),
-    )
-    .parse_next(input)
+fn parse_register<R: Word>(input: &mut &str) -> PResult<Register<R>> {
+    preceded(("Register ", alpha1, ": "), digit1.parse_to().map(Register)).parse_next(input)
 }
 fn parse_program(input: &mut &str) -> PResult<Program> {
     preceded(
@@ -273,35 +320,50 @@ fn parse_program(input: &mut &str) -> PResult<Program> {
     .parse_next(input)
 }
 
-fn solve(input: impl AsRef<str>) -> ProgramOutput {
-    eprintln!("Running with input:\n{}", input.as_ref());
-    let computer = parse_computer
-        .parse_next(&mut input.as_ref())
+/// Runs with `u64` registers, and again with arbitrary precision if they overflow.
+pub fn solve(input: impl AsRef<str>) -> ProgramOutput {
+    log::debug!("Running with input:\n{}", input.as_ref());
+    let computer: Computer = parse_computer
+        .parse_report(input.as_ref())
         .expect("parsable");
-    eprintln!("Parsed!\n");
-    computer.run().output
+    log::debug!("Parsed!");
+    log::debug!("Disassembled:\n{}", Disassembly::of(&computer.program));
+    log::debug!("Decompiled:\n{}", Pseudocode::of(&computer.program));
+    match computer.run() {
+        Ok(computer) => computer.output,
+        Err(fault @ Fault::Overflow { .. }) => {
+            log::debug!("{}, running again with arbitrary precision", fault);
+            let computer: Computer<BigUint> = parse_computer
+                .parse_report(input.as_ref())
+                .expect("parsable");
+            computer
+                .run()
+                .unwrap_or_else(|fault| panic!("{}", fault))
+                .output
+        }
+        Err(fault) => panic!("{}", fault),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::asm::assemble;
 
-    impl From<Vec<ArbitraryUInt>> for Program {
-        fn from(value: Vec<ArbitraryUInt>) -> Self {
-            Program(
-                value
-                    .into_iter()
-                    .map(|v| (v as u8).try_into().expect("assume correct input"))
-                    .collect(),
-            )
-        }
+    fn program(source: &str) -> Program {
+        assemble(source).expect("assembles")
     }
-    impl From<Vec<ArbitraryUInt>> for ProgramOutput {
-        fn from(value: Vec<ArbitraryUInt>) -> Self {
+
+    fn run(computer: Computer) -> Computer {
+        computer.run().expect("runs without faults")
+    }
+
+    impl From<Vec<u8>> for ProgramOutput {
+        fn from(value: Vec<u8>) -> Self {
             ProgramOutput(
                 value
                     .into_iter()
-                    .map(|v| (v as u8).try_into().expect("assume correct input"))
+                    .map(|v| v.try_into().expect("assume correct input"))
                     .collect(),
             )
         }
@@ -311,12 +373,11 @@ mod tests {
     #[test]
     fn register_write() {
         assert_eq!(
-            Computer {
+            run(Computer {
                 c: Register(9),
-                program: Program::from(vec![2, 6]),
+                program: program("bst C"),
                 ..Default::default()
-            }
-            .run()
+            })
             .b
             .0,
             1
@@ -327,12 +388,11 @@ mod tests {
     #[test]
     fn register_with_program_output() {
         assert_eq!(
-            Computer {
+            run(Computer {
                 a: Register(10),
-                program: Program::from(vec![5, 0, 5, 1, 5, 4]),
+                program: program("out 0\nout 1\nout A"),
                 ..Default::default()
-            }
-            .run()
+            })
             .output
             .0,
             ProgramOutput::from(vec![0, 1, 2]).0
@@ -343,12 +403,11 @@ mod tests {
     /// the program 0,1,5,4,3,0 would output 4,2,5,6,7,7,7,7,3,1,0 and leave 0 in register A.
     #[test]
     fn register_write_with_program_output() {
-        let computer = Computer {
+        let computer = run(Computer {
             a: Register(2024),
-            program: Program::from(vec![0, 1, 5, 4, 3, 0]),
+            program: program("loop: adv 1\nout A\njnz loop"),
             ..Default::default()
-        }
-        .run();
+        });
         assert_eq!(
             computer.output.0,
             ProgramOutput::from(vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]).0
@@ -360,12 +419,11 @@ mod tests {
     #[test]
     fn register_write_2() {
         assert_eq!(
-            Computer {
+            run(Computer {
                 b: Register(29),
-                program: Program::from(vec![1, 7]),
+                program: program("bxl 7"),
                 ..Default::default()
-            }
-            .run()
+            })
             .b
             .0,
             26
@@ -378,19 +436,74 @@ mod tests {
     #[test]
     fn multi_register_write() {
         assert_eq!(
-            Computer {
+            run(Computer {
                 b: Register(2024),
                 c: Register(43690),
-                program: Program::from(vec![4, 0]),
+                program: program("bxc"),
                 ..Default::default()
-            }
-            .run()
+            })
             .b
             .0,
             44354
         );
     }
 
+    #[test]
+    fn faults_are_reported() {
+        let fault = |a: u64, b: u64, source: &str| {
+            Computer {
+                a: Register(a),
+                b: Register(b),
+                program: program(source),
+                ..Default::default()
+            }
+            .run()
+            .err()
+        };
+        assert_eq!(
+            fault(0, 0, "bxl 1\nout reserved"),
+            Some(Fault::ReservedOperand(2))
+        );
+        assert_eq!(fault(1 << 40, 63, "adv B"), None);
+        assert_eq!(
+            fault(1 << 40, 64, "adv B"),
+            Some(Fault::Overflow {
+                address: 0,
+                power: "64".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn big_registers_do_not_overflow() {
+        // 2^70 + 5
+        let computer: Computer<BigUint> = parse_computer
+            .parse_report(
+                "Register A: 1180591620717411303429
+Register B: 70
+Register C: 0
+
+Program: 5,4,6,5,5,5",
+            )
+            .unwrap();
+        let ran = computer
+            .with_a(computer.a.0.clone())
+            .run()
+            .expect("runs without faults");
+        assert_eq!(ran.output.to_string(), "5,1");
+        // shifting everything out
+        assert_eq!(
+            computer.with_a(BigUint::from(7u8)).run().unwrap().b.0,
+            BigUint::default()
+        );
+        // with u64 registers the same shift overflows, so it runs again with big ones
+        assert_eq!(
+            solve("Register A: 123\nRegister B: 64\nRegister C: 0\n\nProgram: 7,5,5,6,5,4")
+                .to_string(),
+            "0,3"
+        );
+    }
+
     #[test]
     fn example_solvable() {
         const EXAMPLE: &str = include_str!("EXAMPLE");
@@ -403,7 +516,7 @@ mod tests {
     #[test]
     fn input_solvable() {
         assert_eq!(
-            solve(include_str!("../../inputs/17")).to_string(),
+            solve(util::input_or_skip!(17)).to_string(),
             "4,3,7,1,5,3,0,5,4".to_string()
         );
     }
@@ -1,5 +1,11 @@
 //#![doc = include_str!("../p2.md")]
 
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+use util::ParseReport;
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -11,33 +17,142 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<13>().solve_with(solve);
+use crate::{
+    disasm::Pseudocode,
+    p1::{parse_computer, Computer, Fault, OpCode, ThreeBitValue},
+};
+
+/// Why a program cannot be searched backwards for the `A` that makes it output itself.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Unfit {
+    /// It is not a single loop jumping back to the start from its end.
+    NotALoop,
+    /// It does not shift `A` right by 3 bits exactly once per loop.
+    Shift,
+    /// It does not output exactly once per loop.
+    Outputs(usize),
+    /// No `A` makes it output itself.
+    NoQuine,
+    /// Running it for some `A` faulted.
+    Fault(Fault),
 }
+impl Display for Unfit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Unfit::NotALoop => write!(f, "the program is not a single loop over A"),
+            Unfit::Shift => write!(f, "the program does not shift A by 3 bits once per loop"),
+            Unfit::Outputs(n) => write!(f, "the program outputs {} times per loop, not once", n),
+            Unfit::NoQuine => write!(f, "no value of A makes the program output itself"),
+            Unfit::Fault(fault) => write!(f, "the program faulted: {}", fault),
+        }
+    }
+}
+impl Error for Unfit {}
 
 /// # Problem
 /// The lowest possible initial value for register A,
 /// that makes the program output itself (its source program)?
 /// # Approach
-/// This must have something to do with optimization.
-/// Brute forcing probably wouldn't work.
-/// I can maybe analyze my input for specific patterns that can be optimized,
-/// even if it doesn't generalize to other solutions?
-/// I should start by doing the naive solution for the example though.
-fn solve(input: impl AsRef<str>) -> u64 {
-    todo!()
+/// Brute forcing does not work, as the answer has as many octal digits as
+/// the program is long. The programs (see their [`Pseudocode`]) are a loop
+/// that outputs once per iteration, from the lowest 3 bits of `A` and what is
+/// above them, then shifts those 3 bits out. So the last output only depends
+/// on the highest 3 bits of `A`, the one before on the highest 6 and so on.
+/// This builds `A` backwards from its highest 3 bits, keeping the values for
+/// which running the program outputs the end of itself, and trying their
+/// lowest 3 bits in increasing order such that the first full match is the lowest.
+pub fn solve(input: impl AsRef<str>) -> u64 {
+    let computer = parse_computer
+        .parse_report(input.as_ref())
+        .expect("parsable");
+    lowest_quine_a(&computer).unwrap_or_else(|unfit| panic!("{}", unfit))
+}
+
+fn lowest_quine_a(computer: &Computer) -> Result<u64, Unfit> {
+    let pseudocode = Pseudocode::of(&computer.program);
+    let body = pseudocode.loop_body().ok_or(Unfit::NotALoop)?;
+    let count = |opcode: OpCode| body.iter().filter(|i| i.opcode == opcode).count();
+    let shifts_by_3 = body
+        .iter()
+        .any(|i| i.opcode == OpCode::Adv && i.operand == ThreeBitValue::Three);
+    if count(OpCode::Adv) != 1 || !shifts_by_3 {
+        return Err(Unfit::Shift);
+    }
+    match count(OpCode::Out) {
+        1 => {}
+        outputs => return Err(Unfit::Outputs(outputs)),
+    }
+
+    let program = &computer.program.0;
+    let outputs = |a: u64| computer.with_a(a).run().map(|computer| computer.output.0);
+    // the lowest `A` below `a` whose output is the last `len` values of the program
+    fn search(
+        a: u64,
+        len: usize,
+        program: &[ThreeBitValue],
+        outputs: &impl Fn(u64) -> Result<Vec<ThreeBitValue>, Fault>,
+    ) -> Result<Option<u64>, Fault> {
+        if len > program.len() {
+            return Ok(Some(a >> 3));
+        }
+        for candidate in (0..8).map(|bits| a | bits) {
+            // `A` of 0 would not run the loop a first time
+            if candidate == 0 || outputs(candidate)? != program[program.len() - len..] {
+                continue;
+            }
+            log::trace!("{:o} outputs the last {} values", candidate, len);
+            if let Some(a) = search(candidate << 3, len + 1, program, outputs)? {
+                return Ok(Some(a));
+            }
+        }
+        Ok(None)
+    }
+    search(0, 1, program, &outputs)
+        .map_err(Unfit::Fault)?
+        .ok_or(Unfit::NoQuine)
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn example_solvable() {
         assert_eq!(super::solve(include_str!("EXAMPLE_P2")), 117440);
     }
 
-    #[ignore]
+    #[test]
+    fn unfit_programs_are_reported() {
+        let unfit = |program: &str| {
+            let input = format!(
+                "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: {}",
+                program
+            );
+            lowest_quine_a(&parse_computer.parse_report(&input).unwrap())
+        };
+        assert_eq!(unfit("0,3,5,4"), Err(Unfit::NotALoop));
+        assert_eq!(unfit("0,3,0,3,5,4,3,0"), Err(Unfit::Shift));
+        assert_eq!(unfit("0,1,5,4,3,0"), Err(Unfit::Shift));
+        assert_eq!(unfit("0,3,3,0"), Err(Unfit::Outputs(0)));
+        // outputs a constant, so never itself
+        assert_eq!(unfit("0,3,5,1,3,0"), Err(Unfit::NoQuine));
+        assert_eq!(
+            unfit("2,7,0,3,5,4,3,0"),
+            Err(Unfit::Fault(Fault::ReservedOperand(0)))
+        );
+        // like the inputs, with outputs that depend on more than the lowest 3 bits of A
+        assert_eq!(
+            unfit("2,4,1,3,7,5,0,3,1,5,4,4,5,5,3,0"),
+            Ok(236539226447469)
+        );
+    }
+
+    /// Its answer is not recorded yet, but any answer is right that makes the program output itself.
     #[test]
     fn input_solvable() {
-        assert_eq!(super::solve(include_str!("../../inputs/17")), 0);
+        let input = util::input_or_skip!(17);
+        let computer: Computer = parse_computer.parse_report(input).expect("parsable");
+        let quine = computer.with_a(super::solve(input)).run().expect("runs");
+        assert_eq!(quine.output.0, computer.program.0);
     }
 }
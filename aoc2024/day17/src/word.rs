@@ -0,0 +1,80 @@
+//! What registers hold, and how the computer does its arithmetic on it.
+//! `u64` registers are fast, but fault where `2^O` of a division does not fit
+//! them, which register valued combo operands of 64 and up get to. [`BigUint`]
+//! registers divide by shifting instead, so they never overflow.
+
+use std::{
+    error::Error,
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+use num_bigint::BigUint;
+
+use crate::p1::ThreeBitValue;
+
+pub(crate) trait Word:
+    Clone + Default + Eq + Debug + Display + FromStr<Err: Error + Send + Sync + 'static>
+{
+    fn from_value(value: u8) -> Self;
+
+    fn is_zero(&self) -> bool;
+
+    /// `self / 2^power`, or `None` if the arithmetic overflows.
+    fn div_pow2(&self, power: &Self) -> Option<Self>;
+
+    fn xor(&self, other: &Self) -> Self;
+
+    /// `self % 8`.
+    fn lowest_three_bits(&self) -> ThreeBitValue;
+}
+
+impl Word for u64 {
+    fn from_value(value: u8) -> Self {
+        value.into()
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == 0
+    }
+
+    fn div_pow2(&self, power: &Self) -> Option<Self> {
+        let denominator = 2u64.checked_pow(u32::try_from(*power).ok()?)?;
+        Some(self / denominator)
+    }
+
+    fn xor(&self, other: &Self) -> Self {
+        self ^ other
+    }
+
+    fn lowest_three_bits(&self) -> ThreeBitValue {
+        ((self % 8) as u8).try_into().expect("v % 8 < 8")
+    }
+}
+
+impl Word for BigUint {
+    fn from_value(value: u8) -> Self {
+        value.into()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.bits() == 0
+    }
+
+    fn div_pow2(&self, power: &Self) -> Option<Self> {
+        // shifting by at least every bit there is leaves nothing
+        match u64::try_from(power) {
+            Ok(power) if power < self.bits() => Some(self >> power),
+            _ => Some(BigUint::default()),
+        }
+    }
+
+    fn xor(&self, other: &Self) -> Self {
+        self ^ other
+    }
+
+    fn lowest_three_bits(&self) -> ThreeBitValue {
+        let lowest = self.iter_u32_digits().next().unwrap_or(0) % 8;
+        (lowest as u8).try_into().expect("v % 8 < 8")
+    }
+}
@@ -0,0 +1,31 @@
+mod common;
+pub mod p1;
+pub mod p2;
+
+use std::fmt::Display;
+
+use common::{parse_bytes, Pos, STANDARD_SIZE};
+use p1::STANDARD_COUNT;
+use util::{ParseError, ParseReport, Solution};
+
+/// Both parts place the same falling bytes, so they share a parse.
+pub struct Day18;
+impl Solution for Day18 {
+    type Parsed<'i> = Vec<Pos>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_bytes.parse_report(input)
+    }
+
+    fn part1(bytes: &Self::Parsed<'_>) -> impl Display {
+        p1::shortest_path::<STANDARD_SIZE, STANDARD_COUNT>(bytes)
+    }
+
+    fn part2(bytes: &Self::Parsed<'_>) -> impl Display {
+        p2::first_blocking::<STANDARD_SIZE>(bytes)
+    }
+}
+
+pub fn register(registry: &mut util::Registry) {
+    registry.register_solution::<Day18>(18);
+}
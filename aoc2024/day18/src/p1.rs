@@ -1,26 +1,29 @@
 #![doc = include_str!("../p1.md")]
 
-use common::*;
-use std::collections::{hash_map, HashMap, VecDeque};
-use winnow::Parser;
-
-mod common;
+use crate::common::*;
+use util::grid::Coord;
+use util::search;
+use util::svg::Picture;
+use util::ParseReport;
 
 pub const STANDARD_COUNT: usize = 1024;
-fn main() {
-    util::DayInput::find::<18>().solve_with(solve::<STANDARD_SIZE, STANDARD_COUNT>);
-}
 
 /// # Problem
 /// From input we get some obstacles that may be placed on some 2D map.
 /// The first 1024 obstacles are placed.
 /// How many steps is the fastest route from the top left to the bottom right?
-fn solve<const GRAPH_SIZE: GraphDistance, const BYTE_COUNT: usize>(input: impl AsRef<str>) -> u64 {
-    let all_obstacles = parse_bytes
-        .parse_next(&mut input.as_ref())
-        .expect("parsable");
-    let graph = SetGraph::<GRAPH_SIZE>::from_obstacles::<BYTE_COUNT>(all_obstacles);
-    eprintln!("Graph:\n{}", graph);
+pub fn solve<const GRAPH_SIZE: GraphDistance, const BYTE_COUNT: usize>(
+    input: impl AsRef<str>,
+) -> u64 {
+    let all_obstacles = parse_bytes.parse_report(input.as_ref()).expect("parsable");
+    shortest_path::<GRAPH_SIZE, BYTE_COUNT>(&all_obstacles)
+}
+
+pub fn shortest_path<const GRAPH_SIZE: GraphDistance, const BYTE_COUNT: usize>(
+    all_obstacles: &[Pos],
+) -> u64 {
+    let graph = SetGraph::<GRAPH_SIZE>::from_obstacles::<BYTE_COUNT>(all_obstacles.iter().copied());
+    log::trace!("Graph:\n{}", graph);
     graph.find_shortest_path_through()
 }
 
@@ -38,49 +41,31 @@ impl<const SIZE: GraphDistance> ExactSearch for SetGraph<SIZE> {
     }
 
     fn find_shortest_path_through(&self) -> u64 {
-        fn find_paths<const GRAPH_SIZE: GraphDistance>(
-            graph: &SetGraph<GRAPH_SIZE>,
-            start: Pos,
-            goal: Pos,
-        ) -> HashMap<Pos, Option<Pos>> {
-            let mut frontier = VecDeque::from([start]);
-            let mut came_from: HashMap<Pos, Option<Pos>> = HashMap::from([(start, None)]);
-
-            // create optimal path for every position
-            while let Some(current) = frontier.pop_front() {
-                if current == goal {
-                    break;
-                }
-
-                for valid_neighboor in graph.neighboors(&current) {
-                    if let hash_map::Entry::Vacant(new_entry) = came_from.entry(valid_neighboor) {
-                        new_entry.insert(Some(current));
-                        frontier.push_back(valid_neighboor);
-                    }
-                }
-            }
-            came_from
-        }
-
         let start = Pos { x: 0, y: 0 };
         let goal = Pos { x: SIZE, y: SIZE };
-        let came_from = find_paths(self, start, goal);
-
-        let mut prev = &goal; // assume accessible
-        let mut travel_distance = 0;
-        while let Some(Some(next_backwards_pos)) = came_from.get(prev) {
-            prev = next_backwards_pos;
-            travel_distance += 1;
-        }
-
-        travel_distance
+        let neighbours = |pos: &Pos| {
+            self.neighboors(pos)
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let path =
+            search::bfs(&neighbours, [start], |pos| *pos == goal).expect("goal is reachable");
+        util::svg::export("day18-escape", || {
+            let coord = |pos: &Pos| Coord::new(pos.x as usize, pos.y as usize);
+            let mut picture = Picture::from_text(self);
+            picture
+                .path(path.states.iter().map(coord), [230, 60, 60])
+                .label(coord(&goal), path.cost, [255, 215, 0]);
+            picture
+        });
+        path.cost as u64
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::STANDARD_COUNT;
     use crate::common::STANDARD_SIZE;
-    use crate::STANDARD_COUNT;
 
     #[test]
     fn example_solvable() {
@@ -91,7 +76,7 @@ mod tests {
     #[test]
     fn input_solvable() {
         assert_eq!(
-            super::solve::<STANDARD_SIZE, STANDARD_COUNT>(include_str!("../../inputs/18")),
+            super::solve::<STANDARD_SIZE, STANDARD_COUNT>(util::input_or_skip!(18)),
             246
         );
     }
@@ -1,10 +1,10 @@
 #![doc = include_str!("../p2.md")]
 
-mod common;
-
-use common::*;
+use crate::common::*;
 use itertools::{FoldWhile, Itertools};
-use std::collections::{HashSet, VecDeque};
+use std::collections::HashSet;
+use util::search;
+use util::ParseReport;
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -16,14 +16,12 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<18>().solve_with(solve::<STANDARD_SIZE>);
+pub fn solve<const GRAPH_SIZE: GraphDistance>(input: impl AsRef<str>) -> Pos {
+    let obstacles = parse_bytes.parse_report(input.as_ref()).expect("parsable");
+    first_blocking::<GRAPH_SIZE>(&obstacles)
 }
 
-fn solve<const GRAPH_SIZE: GraphDistance>(input: impl AsRef<str>) -> Pos {
-    let obstacles = parse_bytes
-        .parse_next(&mut input.as_ref())
-        .expect("parsable");
+pub fn first_blocking<const GRAPH_SIZE: GraphDistance>(obstacles: &[Pos]) -> Pos {
     let graph = UnfinalizedGraph::<GRAPH_SIZE> {
         all_obstacles: obstacles,
     };
@@ -32,32 +30,23 @@ fn solve<const GRAPH_SIZE: GraphDistance>(input: impl AsRef<str>) -> Pos {
         .expect("some place")
 }
 
-struct UnfinalizedGraph<const SIZE: GraphDistance> {
-    all_obstacles: Vec<Pos>,
+struct UnfinalizedGraph<'o, const SIZE: GraphDistance> {
+    all_obstacles: &'o [Pos],
 }
-impl<const SIZE: GraphDistance> UnfinalizedGraph<SIZE> {
+impl<const SIZE: GraphDistance> UnfinalizedGraph<'_, SIZE> {
     fn find_last_placed_before_impassable(&self) -> Option<Pos> {
-        /// BFS, early exit (when finding goal)
         fn can_find_goal_with_obstacles<const GRAPH_SIZE: GraphDistance>(
             graph: &SetGraph<GRAPH_SIZE>,
             start: Pos,
             goal: Pos,
         ) -> bool {
-            let mut frontier = VecDeque::from([start]);
-            let mut visited = HashSet::from([start]);
-            while let Some(current) = frontier.pop_front() {
-                if current == goal {
-                    return true;
-                }
-
-                for valid_neighboor in graph.neighboors(&current) {
-                    // is this a new spot?
-                    if visited.insert(valid_neighboor) {
-                        frontier.push_back(valid_neighboor);
-                    }
-                }
-            }
-            false
+            let neighbours = |pos: &Pos| {
+                graph
+                    .neighboors(pos)
+                    .map(|next| (next, ()))
+                    .collect::<Vec<_>>()
+            };
+            search::bfs(&neighbours, [start], |pos| *pos == goal).is_some()
         }
 
         let start = Pos { x: 0, y: 0 };
@@ -84,7 +73,7 @@ impl<const SIZE: GraphDistance> UnfinalizedGraph<SIZE> {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn example_solvable() {
@@ -95,7 +84,7 @@ mod tests {
     #[test]
     fn input_solvable() {
         assert_eq!(
-            solve::<STANDARD_SIZE>(include_str!("../../inputs/18")).to_string(),
+            solve::<STANDARD_SIZE>(util::input_or_skip!(18)).to_string(),
             "22,50"
         );
     }
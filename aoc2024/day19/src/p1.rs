@@ -2,14 +2,9 @@
 
 use itertools::Itertools;
 use std::collections::HashSet;
-use winnow::Parser;
+use util::ParseReport;
 
-mod common;
-use common::*;
-
-fn main() {
-    util::DayInput::find::<19>().solve_with(solve);
-}
+use crate::common::*;
 
 /// # Problem
 /// How many designs are possible?
@@ -27,17 +22,16 @@ fn main() {
 /// A strategy would be to remove composite values from the patterns tried for each design.
 /// The caching we use is the initial + all discovered composites. We should not remove anything
 /// from this cache.
-fn solve(input: impl AsRef<str>) -> usize {
-    let (initial_available, wanted_designs) = parse_input
-        .parse_next(&mut input.as_ref())
-        .expect("parsable");
+pub fn solve(input: impl AsRef<str>) -> usize {
+    let (initial_available, wanted_designs) =
+        parse_input.parse_report(input.as_ref()).expect("parsable");
     let compressed = compress(initial_available.clone());
     let mut cache = initial_available;
     wanted_designs
         .into_iter()
         .enumerate()
         .map(|(i, design)| {
-            eprintln!("{}: {}", i, design.0.iter().join(""));
+            log::trace!("{}: {}", i, design.0.iter().join(""));
             design.test_possible_cached(&compressed, &mut cache)
         })
         .filter(|&result| result)
@@ -89,6 +83,6 @@ mod tests {
     //    #[ignore]
     #[test]
     fn input_solvable() {
-        assert_eq!(super::solve(include_str!("../../inputs/19")), 280);
+        assert_eq!(super::solve(util::input_or_skip!(19)), 280);
     }
 }
@@ -1,21 +1,13 @@
 #![doc = include_str!("../p2.md")]
 
-mod common;
-
-use common::*;
+use crate::common::*;
 use std::collections::{HashMap, HashSet};
-use winnow::Parser;
-
-fn main() {
-    util::DayInput::find::<19>().solve_with(solve);
-}
+use util::ParseReport;
 
 /// # Problem
 /// What is the sum of each design's amount of arrangements/combinations (from available)?
-fn solve(input: impl AsRef<str>) -> u64 {
-    let (available, wanted_designs) = parse_input
-        .parse_next(&mut input.as_ref())
-        .expect("parsable");
+pub fn solve(input: impl AsRef<str>) -> u64 {
+    let (available, wanted_designs) = parse_input.parse_report(input.as_ref()).expect("parsable");
     let mut known_values: HashMap<StripePattern, u64> = HashMap::new();
     wanted_designs
         .into_iter()
@@ -59,7 +51,7 @@ mod tests {
     #[test]
     fn input_solvable() {
         assert_eq!(
-            super::solve(include_str!("../../inputs/19")),
+            super::solve(util::input_or_skip!(19)),
             606411968721181,
             "actual"
         );
@@ -1,7 +1,5 @@
 #![doc = include_str!("../p1.md")]
 
-mod common;
-
 use itertools::Itertools;
 #[allow(unused_imports)]
 use winnow::{
@@ -11,13 +9,9 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<2>().solve_with(solve);
-}
-
 /// Each line is a report,
 /// and each report is a list of levels (numbers, separated by spaces).
-fn solve(input: impl AsRef<str>) -> u32 {
+pub fn solve(input: impl AsRef<str>) -> u32 {
     input
         .as_ref()
         .lines()
@@ -43,11 +37,11 @@ fn solve(input: impl AsRef<str>) -> u32 {
 mod p1test {
     #[test]
     fn sample_solvable() {
-        assert_eq!(super::solve(super::common::SAMPLE), 2);
+        assert_eq!(super::solve(crate::common::SAMPLE), 2);
     }
     #[ignore]
     #[test]
     fn input_solvable() {
-        assert_eq!(super::solve(include_str!("../../inputs/2")), 639);
+        assert_eq!(super::solve(util::input_or_skip!(2)), 639);
     }
 }
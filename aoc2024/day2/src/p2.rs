@@ -2,13 +2,7 @@
 
 use itertools::Itertools;
 
-mod common;
-
-fn main() {
-    util::DayInput::find::<2>().solve_with(solve);
-}
-
-fn solve(input: impl AsRef<str>) -> u32 {
+pub fn solve(input: impl AsRef<str>) -> u32 {
     input
         .as_ref()
         .lines()
@@ -46,7 +40,7 @@ fn solve(input: impl AsRef<str>) -> u32 {
 mod p2test {
     #[test]
     fn sample_solvable() {
-        assert_eq!(super::solve(super::common::SAMPLE), 4);
+        assert_eq!(super::solve(crate::common::SAMPLE), 4);
     }
 
     // here is a manual snapshot test. A better solution would be to use `insta` and
@@ -54,6 +48,6 @@ mod p2test {
     #[ignore]
     #[test]
     fn input_solvable() {
-        assert_eq!(super::solve(include_str!("../../inputs/2")), 674);
+        assert_eq!(super::solve(util::input_or_skip!(2)), 674);
     }
 }
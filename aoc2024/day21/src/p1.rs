@@ -2,6 +2,7 @@
 
 use itertools::Itertools;
 use std::fmt::{Display, Formatter};
+use util::ParseReport;
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -13,10 +14,6 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<21>().solve_with(solve);
-}
-
 /// # Problem
 /// Find the sum of the complexities for each code.
 ///
@@ -103,9 +100,9 @@ fn main() {
 /// An observation to have here is that since knowing the innermost/last level is trivial,
 /// it means we only really need to implement searches for all the other levels.
 /// This eliminates the need for a search that handles anything other than *directional* pads.
-fn solve(input: impl AsRef<str>) -> u64 {
+pub fn solve(input: impl AsRef<str>) -> u64 {
     let codes = parse_sequences
-        .parse_next(&mut input.as_ref())
+        .parse_report(input.as_ref())
         .expect("parsable");
     codes
         .into_iter()
@@ -448,14 +445,9 @@ trait TravelCost {
 
 #[cfg(test)]
 mod tests {
+    #[ignore = "unsolved"]
     #[test]
     fn example_solvable() {
         assert_eq!(super::solve(include_str!("EXAMPLE")), 126384);
     }
-
-    #[ignore]
-    #[test]
-    fn input_solvable() {
-        assert_eq!(super::solve(include_str!("../../inputs/21")), 0);
-    }
 }
@@ -11,24 +11,15 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<21>().solve_with(solve);
-}
-
-fn solve(input: impl AsRef<str>) -> u64 {
+pub fn solve(input: impl AsRef<str>) -> u64 {
     todo!()
 }
 
 #[cfg(test)]
 mod tests {
+    #[ignore = "unsolved"]
     #[test]
     fn example_solvable() {
         assert_eq!(super::solve(include_str!("EXAMPLE")), todo!());
     }
-
-    #[ignore]
-    #[test]
-    fn input_solvable() {
-        assert_eq!(super::solve(include_str!("../../inputs/21")), todo!());
-    }
 }
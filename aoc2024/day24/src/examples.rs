@@ -0,0 +1,22 @@
+//! Generated by `aoc ingest` from the puzzle descriptions.
+//! Ingesting again overwrites this file.
+
+#[test]
+fn p1_example_solvable() {
+    let mut registry = util::Registry::new();
+    crate::register(&mut registry);
+    let answer = registry.answer(24, 1, include_str!("P1_EXAMPLE"));
+    assert_eq!(answer.expect("part is registered").unwrap(), "2024");
+}
+
+#[test]
+#[ignore = "unsolved"]
+fn p2_example_solvable() {
+    let mut registry = util::Registry::new();
+    crate::register(&mut registry);
+    let answer = registry.answer(24, 2, include_str!("P2_EXAMPLE"));
+    assert_eq!(
+        answer.expect("part is registered").unwrap(),
+        "aaa,aoc,bbb,ccc,eee,ooo,z24,z99"
+    );
+}
@@ -0,0 +1,35 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+
+mod common;
+pub mod p1;
+pub mod p2;
+#[cfg(test)]
+mod examples;
+
+use std::fmt::Display;
+
+use common::{parse_device, Connections, States};
+use util::{ParseError, ParseReport, Solution};
+
+/// Both parts work on the same device, so they share a parse.
+pub struct Day24;
+impl Solution for Day24 {
+    type Parsed<'i> = (States<'i>, Connections<'i>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_device.parse_report(input)
+    }
+
+    fn part1((states, connections): &Self::Parsed<'_>) -> impl Display {
+        p1::z_output(states, connections)
+    }
+
+    fn part2((states, connections): &Self::Parsed<'_>) -> impl Display {
+        p2::swapped_outputs(states, connections)
+    }
+}
+
+pub fn register(registry: &mut util::Registry) {
+    registry.register_solution::<Day24>(24);
+}
@@ -1,26 +1,22 @@
 #![doc = include_str!("../p1.md")]
 
-use common::*;
+use crate::common::*;
 use itertools::Itertools;
-use winnow::Parser;
-
-mod common;
-
-fn main() {
-    util::DayInput::find::<24>().solve_with(solve);
-}
+use util::ParseReport;
 
 /// # Problem
 /// What is the number produced by the bits of the `z`-wires at the end of the simulation?
 /// # Solution
 /// The "simulation" can be calculated from the output nodes (`z`-wires) by going through the
 /// connection graph. We assume that all `z`-wires are present in the `connections` data.
-fn solve(input: impl AsRef<str>) -> u64 {
+pub fn solve(input: impl AsRef<str>) -> u64 {
     // get initial states and connections
-    let (mut states, connections) = parse_device
-        .parse_next(&mut input.as_ref())
-        .expect("parsable");
+    let (states, connections) = parse_device.parse_report(input.as_ref()).expect("parsable");
+    z_output(&states, &connections)
+}
 
+pub fn z_output(initial_states: &States, connections: &Connections) -> u64 {
+    let mut states = initial_states.clone();
     connections
         .iter()
         .filter(|connection| connection.dest.0.starts_with("z"))
@@ -32,7 +28,7 @@ fn solve(input: impl AsRef<str>) -> u64 {
                 .parse::<u8>()
                 .unwrap()
         })
-        .map(|connection| descend_graph_cached(&connection.dest, &connections, &mut states))
+        .map(|connection| descend_graph_cached(&connection.dest, connections, &mut states))
         .enumerate()
         .filter_map(|(i, state)| {
             if state {
@@ -54,11 +50,17 @@ mod tests {
     fn larger_solvable() {
         assert_eq!(super::solve(include_str!("EXAMPLE_LARGER")), 2024);
     }
+    #[test]
+    fn larger_solvable_from_model() {
+        use util::Solution;
+        let parsed = crate::Day24::parse(include_str!("EXAMPLE_LARGER")).unwrap();
+        assert_eq!(crate::Day24::part1(&parsed).to_string(), "2024");
+    }
 
     #[test]
     fn input_solvable() {
         assert_eq!(
-            super::solve(include_str!("../../inputs/24")),
+            super::solve(util::input_or_skip!(24)),
             66055249060558
         );
     }
@@ -3,6 +3,7 @@
 use itertools::Itertools;
 use std::collections::HashSet;
 use std::fmt::{Display, Formatter};
+use util::ParseReport;
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -14,10 +15,6 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<25>().solve_with(solve);
-}
-
 /// # Problem
 /// Some locks, some keys.
 /// A key fits with a lock if columns do not overlap.
@@ -28,9 +25,9 @@ fn main() {
 /// # Solution
 /// If every column value in a key is less than or equal to that of a lock's,
 /// the key fits. The count of a filtered iterator from combinations.
-fn solve(input: impl AsRef<str>) -> u64 {
+pub fn solve(input: impl AsRef<str>) -> u64 {
     let (locks, keys) = parse_locks_and_keys
-        .parse_next(&mut input.as_ref())
+        .parse_report(input.as_ref())
         .expect("parsable");
     locks
         .iter()
@@ -195,6 +192,6 @@ mod tests {
     //    #[ignore]
     #[test]
     fn input_solvable() {
-        assert_eq!(super::solve(include_str!("../../inputs/25")), 3451);
+        assert_eq!(super::solve(util::input_or_skip!(25)), 3451);
     }
 }
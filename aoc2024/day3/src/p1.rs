@@ -1,5 +1,6 @@
 #![doc = include_str!("../p1.md")]
 
+use util::ParseReport;
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -9,13 +10,9 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<3>().solve_with(solve);
-}
-
-fn solve(input: impl AsRef<str>) -> u32 {
+pub fn solve(input: impl AsRef<str>) -> u32 {
     parse_all_components
-        .parse_next(&mut input.as_ref())
+        .parse_report(input.as_ref())
         .expect("parsable")
         .into_iter()
         .map(|(a, b)| a * b)
@@ -46,6 +43,6 @@ pub mod p1test {
     #[ignore]
     #[test]
     fn input_solvable() {
-        assert_eq!(super::solve(include_str!("../../inputs/3")), 179571322);
+        assert_eq!(super::solve(util::input_or_skip!(3)), 179571322);
     }
 }
@@ -1,5 +1,6 @@
 #![doc = include_str!("../p2.md")]
 
+use util::ParseReport;
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -9,10 +10,6 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<3>().solve_with(solve);
-}
-
 #[derive(Debug, Clone)]
 enum State {
     Read,
@@ -53,9 +50,10 @@ fn parse_all_instructions(input: &mut &str) -> PResult<Vec<Instruction>> {
     .parse_next(input)
 }
 
-fn solve(input: impl AsRef<str>) -> u32 {
+pub fn solve(input: impl AsRef<str>) -> u32 {
     // create vec of instructions
-    parse_all_instructions(&mut input.as_ref())
+    parse_all_instructions
+        .parse_report(input.as_ref())
         .expect("parsable")
         .into_iter()
         // execute instructions
@@ -87,6 +85,6 @@ pub mod p2test {
     #[ignore]
     #[test]
     fn input_solvable() {
-        assert_eq!(super::solve(include_str!("../../inputs/3")), 103811193);
+        assert_eq!(super::solve(util::input_or_skip!(3)), 103811193);
     }
 }
@@ -0,0 +1,11 @@
+#![feature(iter_map_windows)]
+
+pub mod p1;
+pub mod p2;
+#[cfg(test)]
+mod examples;
+
+pub fn register(registry: &mut util::Registry) {
+    registry.register(4, 1, |input| p1::solve(input));
+    registry.register(4, 2, |input| p2::solve(input));
+}
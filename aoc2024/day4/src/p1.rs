@@ -1,4 +1,3 @@
-#![feature(iter_map_windows)]
 #![doc = include_str!("../p1.md")]
 
 use std::collections::hash_map::IntoValues;
@@ -13,10 +12,6 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<4>().solve_with(solve);
-}
-
 /// # Constraints
 /// The word may appear in any direction, all 8 directions (cardinal *and*
 /// diagonal) Input is a rectangle, meaning every line is the same length
@@ -35,7 +30,7 @@ fn main() {
 /// it, is possible by starting the iterator with a certain offset or ending it
 /// with a certain offset. ### Note:
 /// The sample is 10 characters wide.
-fn solve(input: impl AsRef<str>) -> u32 {
+pub fn solve(input: impl AsRef<str>) -> u32 {
     let lines = input.as_ref().lines();
     let line_count = lines.clone().count();
 
@@ -107,6 +102,6 @@ mod p1test {
     //#[ignore]
     #[test]
     fn input_solvable() {
-        assert_eq!(super::solve(include_str!("../../inputs/4")), 2536);
+        assert_eq!(super::solve(util::input_or_skip!(4)), 2536);
     }
 }
@@ -1,4 +1,3 @@
-#![feature(iter_map_windows)]
 #![doc = include_str!("../p2.md")]
 
 use itertools::Itertools;
@@ -11,15 +10,11 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<4>().solve_with(solve);
-}
-
 /// Count amount of X's (diagonal lines; cardinal can be ignored) made of `MAS`,
 /// where either direction is allowed for each line.
 /// # Solution A:
 /// For
-fn solve(input: impl AsRef<str>) -> u32 {
+pub fn solve(input: impl AsRef<str>) -> u32 {
     input
         .as_ref()
         .lines()
@@ -90,6 +85,6 @@ mod p2test {
     //#[ignore]
     #[test]
     fn input_solvable() {
-        assert_eq!(super::solve(include_str!("../../inputs/4")), 1875);
+        assert_eq!(super::solve(util::input_or_skip!(4)), 1875);
     }
 }
@@ -1,5 +1,6 @@
 #![doc = include_str!("../p1.md")]
 use itertools::Itertools;
+use util::ParseReport;
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -9,10 +10,6 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<5>().solve_with(solve);
-}
-
 /// # Specification
 /// Some ordering rules are given, one per line.
 /// These are numbers that should be before (are less than) other
@@ -45,8 +42,8 @@ fn main() {
 /// and 1<6, that does not mean 1<3; for all intents and purposes they are equal
 /// values. This means there are multiple valid sorting configurations for a set
 /// of updates.
-fn solve(input: impl AsRef<str>) -> u32 {
-    let (x_smaller_than_y, updates) = parse.parse_next(&mut input.as_ref()).expect("parsable");
+pub fn solve(input: impl AsRef<str>) -> u32 {
+    let (x_smaller_than_y, updates) = parse.parse_report(input.as_ref()).expect("parsable");
     let rule_map = x_smaller_than_y.into_iter().into_group_map();
     updates
         .into_iter()
@@ -106,6 +103,6 @@ mod p1test {
     //#[ignore]
     #[test]
     fn input_solvable() {
-        assert_eq!(super::solve(include_str!("../../inputs/5")), 5091);
+        assert_eq!(super::solve(util::input_or_skip!(5)), 5091);
     }
 }
@@ -6,6 +6,7 @@ use std::{
     str::FromStr,
 };
 
+use util::ParseReport;
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -16,10 +17,6 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<6>().solve_with(solve);
-}
-
 /// # Problem
 /// How many distinct (unique) positions will the guard visit before leaving the
 /// map? # Solution
@@ -29,7 +26,7 @@ fn main() {
 /// Guard begins somewhere, with a starting direction.
 /// We can get position and direction while parsing.
 /// While parsing, we also get positions of every obstacle (`#`).
-fn solve(input: impl AsRef<str>) -> u64 {
+pub fn solve(input: impl AsRef<str>) -> u64 {
     let sim = input.as_ref().parse::<SimulationState>().expect("parsable");
     sim.finish(HashSet::new()).visited.len() as u64
 }
@@ -64,11 +61,14 @@ impl Display for SimulationState {
 impl SimulationState {
     fn finish(mut self, mut visited: HashSet<Position>) -> CompletedSimulation {
         self.step_till_completed(&mut visited);
-        CompletedSimulation { sim: self, visited }
+        let completed = CompletedSimulation { sim: self, visited };
+        util::viz::frame(&completed);
+        completed
     }
 
     fn step_till_completed(&mut self, visited: &mut HashSet<Position>) {
         while self.guard.pos.is_inside(&self.bounds) {
+            util::viz::frame(Patrol { sim: self, visited });
             self.step_and_or_turn(visited);
         }
     }
@@ -141,15 +141,15 @@ impl MapItem {
     }
 }
 impl FromStr for SimulationState {
-    type Err = ErrMode<ContextError>;
+    type Err = util::ParseError;
 
-    fn from_str(mut s: &str) -> Result<Self, Self::Err> {
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         let bounds = MapBounds {
             width: s.lines().next().expect("first line").chars().count(),
             height: s.lines().count(),
         };
         parse_content
-            .parse_next(&mut s)
+            .parse_report(s)
             .map(|(guard, obstacles)| SimulationState {
                 guard,
                 obstacles,
@@ -286,6 +286,21 @@ struct CompletedSimulation {
     visited: HashSet<Position>,
 }
 impl Display for CompletedSimulation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Patrol {
+            sim: &self.sim,
+            visited: &self.visited,
+        }
+        .fmt(f)
+    }
+}
+
+/// A simulation along with the positions visited so far.
+struct Patrol<'s> {
+    sim: &'s SimulationState,
+    visited: &'s HashSet<Position>,
+}
+impl Display for Patrol<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         for y in 0..(self.sim.bounds.height as i64) {
             for x in 0..(self.sim.bounds.width as i64) {
@@ -384,7 +399,7 @@ mod tests {
 
     #[test]
     fn input_solvable() {
-        const INPUT: &str = include_str!("../../inputs/6");
-        assert_eq!(super::solve(INPUT), 4964);
+        let input = util::input_or_skip!(6);
+        assert_eq!(super::solve(input), 4964);
     }
 }
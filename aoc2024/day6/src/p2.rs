@@ -9,10 +9,6 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<6>().solve_with(solve);
-}
-
 /// # Problem
 /// Figure out how many different ways you can make the guard walk in an
 /// infinite loop, with only a single added obstacle.
@@ -28,14 +24,6 @@ fn main() {
 /// This can be done with BFS. Go along the standard guard path, and for every
 /// step check if the different path created by an obstacle creates an infinite
 /// loop or not, using the above method.
-fn solve(input: impl AsRef<str>) -> u64 {
+pub fn solve(input: impl AsRef<str>) -> u64 {
     todo!()
 }
-#[cfg(test)]
-mod tests {
-
-    #[test]
-    fn input_solvable() {
-        assert_eq!(super::solve(include_str!("../../inputs/6")), 0);
-    }
-}
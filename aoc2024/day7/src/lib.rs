@@ -0,0 +1,10 @@
+pub mod p1;
+pub mod p2;
+mod parse;
+#[cfg(test)]
+mod examples;
+
+pub fn register(registry: &mut util::Registry) {
+    registry.register(7, 1, |input| p1::solve(input));
+    registry.register(7, 2, |input| p2::solve(input));
+}
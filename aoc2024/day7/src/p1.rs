@@ -1,8 +1,9 @@
 #![doc = include_str!("../p1.md")]
 
-mod parse;
+use crate::parse;
 
 use itertools::{repeat_n, Itertools};
+use util::ParseReport;
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -12,19 +13,15 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<7>().solve_with(solve);
-}
-
 #[derive(Debug)]
 enum Operation {
     Add,
     Mul,
 }
 
-fn solve(input: impl AsRef<str>) -> u64 {
+pub fn solve(input: impl AsRef<str>) -> u64 {
     let equations = parse::parse_equations
-        .parse_next(&mut input.as_ref())
+        .parse_report(input.as_ref())
         .expect("parsable");
     equations
         .into_iter()
@@ -68,10 +65,10 @@ mod p1test {
     #[test]
     fn input_solvable() {
         assert_ne!(
-            super::solve(include_str!("../../inputs/7")),
+            super::solve(util::input_or_skip!(7)),
             1228638627,
             "this is too low"
         );
-        assert_eq!(super::solve(include_str!("../../inputs/7")), 4364915411363);
+        assert_eq!(super::solve(util::input_or_skip!(7)), 4364915411363);
     }
 }
@@ -1,7 +1,8 @@
 #![doc = include_str!("../p2.md")]
-mod parse;
+use crate::parse;
 
 use itertools::{repeat_n, Itertools};
+use util::ParseReport;
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -11,10 +12,6 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<7>().solve_with(solve);
-}
-
 #[derive(Debug)]
 enum Operation {
     Add,
@@ -22,9 +19,9 @@ enum Operation {
     Concat,
 }
 
-fn solve(input: impl AsRef<str>) -> u64 {
+pub fn solve(input: impl AsRef<str>) -> u64 {
     let equations = parse::parse_equations
-        .parse_next(&mut input.as_ref())
+        .parse_report(input.as_ref())
         .expect("parsable");
     equations
         .into_iter()
@@ -73,6 +70,6 @@ mod p2test {
     //    #[ignore]
     #[test]
     fn input_solvable() {
-        assert_eq!(super::solve(include_str!("../../inputs/7")), 38322057216320);
+        assert_eq!(super::solve(util::input_or_skip!(7)), 38322057216320);
     }
 }
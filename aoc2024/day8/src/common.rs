@@ -6,6 +6,7 @@ use std::{
 };
 
 use itertools::Itertools;
+use util::ParseReport;
 use winnow::stream::AsChar;
 #[allow(unused_imports)]
 use winnow::{
@@ -91,9 +92,9 @@ impl Display for Map {
     }
 }
 impl FromStr for Map {
-    type Err = ErrMode<ContextError>;
+    type Err = util::ParseError;
 
-    fn from_str(mut input: &str) -> Result<Self, Self::Err> {
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
         fn parse_antenna_positions(input: &mut &str) -> PResult<HashMap<char, Vec<Position>>> {
             separated(
                 1..,
@@ -125,7 +126,7 @@ impl FromStr for Map {
         let width = input.lines().next().expect("first line").chars().count();
         let height = input.lines().count();
         parse_antenna_positions
-            .parse_next(&mut input)
+            .parse_report(input)
             .map(|data| Self {
                 width,
                 height,
@@ -1,20 +1,13 @@
 #![doc = include_str!("../p1.md")]
 
-mod common;
-
 use std::{
     collections::HashSet,
     fmt::{Display, Formatter},
     str::FromStr,
 };
 
-use common::*;
+use crate::common::*;
 use itertools::Itertools;
-use winnow::error::{ContextError, ErrMode};
-
-fn main() {
-    util::DayInput::find::<8>().solve_with(solve);
-}
 
 /// # Problem
 /// Given a map of antennas of different frequencies, how many antinodes are
@@ -26,7 +19,7 @@ fn main() {
 /// location, calculate every possible antinode location, filtering out any that
 /// would go outside the map. I think the difficulty comes from parsing this
 /// into usable data.
-fn solve(input: impl AsRef<str>) -> u64 {
+pub fn solve(input: impl AsRef<str>) -> u64 {
     input
         .as_ref()
         .parse::<AntinodeMap>()
@@ -62,7 +55,7 @@ impl Display for AntinodeMap {
 }
 // noinspection DuplicatedCode
 impl FromStr for AntinodeMap {
-    type Err = ErrMode<ContextError>;
+    type Err = util::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         s.parse::<Map>().map(|map| Self {
@@ -75,7 +68,7 @@ impl FromStr for AntinodeMap {
 #[cfg(test)]
 mod tests {
 
-    use super::common::tests::{
+    use crate::common::tests::{
         BASIC_P1_ANTINODES, COMPLEX, COMPLEX_P1_ANTINODES, INTERMEDIATE_P1_ANTINODES_1,
         INTERMEDIATE_P1_ANTINODES_2,
     };
@@ -127,13 +120,13 @@ mod tests {
 
     #[test]
     fn displays_with_antinodes() {
-        super::common::tests::displays_with_antinodes::<super::AntinodeMap>(&P1_ANTINODES);
+        crate::common::tests::displays_with_antinodes::<super::AntinodeMap>(&P1_ANTINODES);
     }
 
     #[test]
     fn input_solvable() {
-        const INPUT: &str = include_str!("../../inputs/8");
-        eprintln!("Map:\n{}", INPUT.parse::<super::AntinodeMap>().unwrap());
-        assert_eq!(super::solve(INPUT), 392);
+        let input = util::input_or_skip!(8);
+        eprintln!("Map:\n{}", input.parse::<super::AntinodeMap>().unwrap());
+        assert_eq!(super::solve(input), 392);
     }
 }
@@ -1,14 +1,12 @@
 #![doc = include_str!("../p2.md")]
 
-mod common;
-
 use std::{
     collections::HashSet,
     fmt::{Display, Formatter},
     str::FromStr,
 };
 
-use common::{Map, *};
+use crate::common::{Map, *};
 use itertools::Itertools;
 #[allow(unused_imports)]
 use winnow::{
@@ -19,10 +17,6 @@ use winnow::{
     {PResult, Parser},
 };
 
-fn main() {
-    util::DayInput::find::<8>().solve_with(solve);
-}
-
 /// # Problem
 /// Same as before, but instead of calculating single spots on each side,
 /// we generate lines that have antinodes for every discrete position.
@@ -30,7 +24,7 @@ fn main() {
 /// Is the line only contiguous if the antennas are next to each other?
 /// # Solution
 /// Iterate over every spot until we are out of bounds.
-fn solve(input: impl AsRef<str>) -> u64 {
+pub fn solve(input: impl AsRef<str>) -> u64 {
     input
         .as_ref()
         .parse::<AntinodeMap>()
@@ -90,7 +84,7 @@ impl Display for AntinodeMap {
 }
 // noinspection DuplicatedCode
 impl FromStr for AntinodeMap {
-    type Err = ErrMode<ContextError>;
+    type Err = util::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         s.parse::<Map>().map(|map| Self {
@@ -102,13 +96,13 @@ impl FromStr for AntinodeMap {
 
 #[cfg(test)]
 mod tests {
-    use super::common::tests::{COMPLEX_P2_ANTINODES, T_P1_ANTINODES};
+    use crate::common::tests::{COMPLEX_P2_ANTINODES, T_P1_ANTINODES};
 
     const P2_ANTINODES: [&str; 2] = [T_P1_ANTINODES, COMPLEX_P2_ANTINODES];
 
     #[test]
     fn displays_with_antinodes() {
-        super::common::tests::displays_with_antinodes::<super::AntinodeMap>(&P2_ANTINODES);
+        crate::common::tests::displays_with_antinodes::<super::AntinodeMap>(&P2_ANTINODES);
     }
 
     #[test]
@@ -125,6 +119,6 @@ mod tests {
 
     #[test]
     fn input_solvable() {
-        assert_eq!(super::solve(include_str!("../../inputs/8")), 1235);
+        assert_eq!(super::solve(util::input_or_skip!(8)), 1235);
     }
 }
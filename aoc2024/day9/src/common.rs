@@ -3,6 +3,7 @@ use std::{
     str::FromStr,
 };
 
+use util::ParseReport;
 #[allow(unused_imports)]
 use winnow::{
     ascii::*,
@@ -42,10 +43,10 @@ impl Display for DiskMap {
     }
 }
 impl FromStr for DiskMap {
-    type Err = ErrMode<ContextError>;
+    type Err = util::ParseError;
 
-    fn from_str(mut s: &str) -> Result<Self, Self::Err> {
-        parse_disk_map.parse_next(&mut s)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_disk_map.parse_report(s)
     }
 }
 
@@ -0,0 +1,11 @@
+mod common;
+pub mod p1;
+pub mod p2;
+#[cfg(test)]
+mod examples;
+
+pub fn register(registry: &mut util::Registry) {
+    registry.register(9, 1, |input| p1::solve(input));
+    registry.register(9, 2, |input| p2::solve(input));
+    registry.normalize(9, util::Normalize::ALL);
+}
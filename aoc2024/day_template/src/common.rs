@@ -0,0 +1,3 @@
+/// What both parts are about, placeholder for the real model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line(pub String);
@@ -0,0 +1,9 @@
+mod common;
+pub mod p1;
+pub mod p2;
+mod parse;
+
+pub fn register(registry: &mut util::Registry) {
+	registry.register(_, 1, |input| p1::solve(input));
+	registry.register(_, 2, |input| p2::solve(input));
+}
@@ -11,24 +11,6 @@ use winnow::{
 	{PResult, Parser}
 };
 
-fn main() {
-	util::DayInput::find::<_>().solve_with(solve);
-}
-
-fn solve(input: impl AsRef<str>) -> u64 {
+pub fn solve(input: impl AsRef<str>) -> u64 {
 	todo!()
-}
-
-#[cfg(test)]
-mod tests {
-	#[test]
-	fn example_solvable() {
-		assert_eq!(super::solve(include_str!("EXAMPLE")), todo!());
-	}
-
-	#[ignore]
-	#[test]
-	fn input_solvable() {
-		assert_eq!(super::solve(include_str!("../../inputs/_")), todo!());
-	}
 }
\ No newline at end of file
@@ -0,0 +1,19 @@
+#[allow(unused_imports)]
+use winnow::{
+	ascii::*,
+	combinator::*,
+	error::*,
+	prelude::*,
+	stream::*,
+	token::*,
+	{PResult, Parser}
+};
+
+use crate::common::Line;
+
+pub fn parse_lines(input: &mut &str) -> PResult<Vec<Line>> {
+	separated(1.., parse_line, line_ending).parse_next(input)
+}
+fn parse_line(input: &mut &str) -> PResult<Line> {
+	till_line_ending.map(|line: &str| Line(line.to_string())).parse_next(input)
+}
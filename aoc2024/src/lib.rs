@@ -0,0 +1,31 @@
+//! The solvers of every 2024 day crate, for the `aoc` runner and for running
+//! them alongside other years.
+
+use util::Registry;
+
+/// Every day crate, registered in order.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    day1::register(&mut registry);
+    day2::register(&mut registry);
+    day3::register(&mut registry);
+    day4::register(&mut registry);
+    day5::register(&mut registry);
+    day6::register(&mut registry);
+    day7::register(&mut registry);
+    day8::register(&mut registry);
+    day9::register(&mut registry);
+    day10::register(&mut registry);
+    day11::register(&mut registry);
+    day12::register(&mut registry);
+    day13::register(&mut registry);
+    day14::register(&mut registry);
+    day15::register(&mut registry);
+    day17::register(&mut registry);
+    day18::register(&mut registry);
+    day19::register(&mut registry);
+    day21::register(&mut registry);
+    day24::register(&mut registry);
+    day25::register(&mut registry);
+    registry
+}
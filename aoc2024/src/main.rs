@@ -0,0 +1,561 @@
+//! Runs the registered solvers of every 2024 day crate.
+//!
+//! ```text
+//! aoc [DAYS] [--part N] [--input PATH] [--inputs DIR]
+//!     [--check] [--record] [--reject HINT] [--answers PATH]
+//!     [--viz OUTPUT] [--fps N] [--svg DIR]
+//! aoc bench [DAYS] [--part N] [--input PATH] [--inputs DIR] [--runs N] [--json PATH]
+//!     [--history PATH] [--no-save]
+//! aoc ingest [DAYS]
+//! aoc new-day DAY [--description]
+//! ```
+//! Every command also takes `--log FILTER`, with levels per module like
+//! `info,day12=trace` (see `util::trace`), defaulting to `AOC_LOG` or else `warn`.
+//! Renders of whole grids and machine states are logged at `trace`.
+//!
+//! `DAYS` is a single day (`6`) or a range (`1..=12`, `1..13`), defaulting to
+//! every day. `--input` overrides the input file when running a single day.
+//! Missing inputs are fetched into `--inputs` with the session cookie from
+//! `AOC_SESSION` or the `.session` file, from `AOC_BASE_URL` if set.
+//! Inputs get the cleanups their day declares (see `util::Normalize`).
+//! A part that panics does not end the run: `todo!()` and `unimplemented!()`
+//! parts are reported as unsolved, other panics as failed (see `util::Outcome`),
+//! and the run ends with how many parts were solved.
+//! `--check` compares every answer against the answers file, and `--record`
+//! stores the answers of this run in it as verified. `--reject` stores the
+//! answer of a single day and part as rejected by the site, with a `too-high`,
+//! `too-low` or `wrong` hint, which later runs warn about before submitting.
+//! `--viz` records the frames the solvers of a single day push (see
+//! `util::viz`), then replays them in the terminal (`-`) or writes them to an
+//! animated `.gif` or `.png` (APNG), at `--fps` frames per second (default 30).
+//! `--svg` writes the pictures solvers draw of their grids (see `util::svg`),
+//! like regions and paths, into a directory.
+//!
+//! `bench` times the parse and every part separately over `--runs` runs
+//! (default 10), printing min/median/max per phase, and writes the same report
+//! as JSON to `--json` if given. Every phase is then compared against the last
+//! timing of it on this machine in the history file (`--history`, default
+//! `bench-history`), flagging slowdowns that are larger than the noise of both
+//! runs, before the new timings are appended to it (unless `--no-save`).
+//! Parts that panic are listed as unsolved or failed instead of timed.
+//! The machine is the host name, or `AOC_MACHINE` if set. Timings of debug
+//! and release builds do not compare, so they are only compared against
+//! timings of the same profile.
+//!
+//! `ingest` reads the example and its answer out of the `p1.md` and `p2.md`
+//! puzzle descriptions of every day crate, writing them as fixtures next to
+//! its sources along with a generated `examples.rs` test module.
+//!
+//! `new-day` creates the crate of a day from `day_template`, and registers it
+//! in the workspace and with this runner. `--description` also fetches the
+//! puzzle page (as far as it is unlocked) into `p1.md` and `p2.md`.
+//! Its examples are tested once they are ingested.
+
+use std::{io, ops::RangeInclusive, path::PathBuf, process::ExitCode, time::SystemTime};
+
+use util::{
+    isolate, trace::Filter, viz, Answers, BenchHistory, BenchReport, DayInput, Fetcher, Hint,
+    Origin, Outcome, Progress, Registry, Verdict,
+};
+
+const USAGE: &str = "usage: aoc [DAYS] [--part N] [--input PATH] [--inputs DIR] \
+                     [--check] [--record] [--reject HINT] [--answers PATH]
+           [--viz OUTPUT] [--fps N] [--svg DIR]
+       aoc bench [DAYS] [--part N] [--input PATH] [--inputs DIR] [--runs N] [--json PATH]
+                 [--history PATH] [--no-save]
+       aoc ingest [DAYS]
+       aoc new-day DAY [--description]
+every command takes [--log FILTER]";
+
+/// Holds the session cookie used to fetch missing inputs, unless `AOC_SESSION` is set.
+const SESSION_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/.session");
+
+enum Command {
+    Run,
+    Bench,
+    Ingest,
+    NewDay,
+}
+
+struct Args {
+    command: Command,
+    days: RangeInclusive<u8>,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    inputs: PathBuf,
+    check: bool,
+    record: bool,
+    reject: Option<Hint>,
+    answers: PathBuf,
+    runs: usize,
+    json: Option<PathBuf>,
+    history: PathBuf,
+    save: bool,
+    description: bool,
+    log: Option<Filter>,
+    viz: Option<viz::Output>,
+    fps: u32,
+    svg: Option<PathBuf>,
+}
+impl Args {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Args {
+            command: Command::Run,
+            days: 1..=25,
+            part: None,
+            input: None,
+            inputs: PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs")),
+            check: false,
+            record: false,
+            reject: None,
+            answers: PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/answers")),
+            runs: 10,
+            json: None,
+            history: PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/bench-history")),
+            save: true,
+            description: false,
+            log: None,
+            viz: None,
+            fps: 30,
+            svg: None,
+        };
+        let mut args = args.peekable();
+        if args.next_if(|arg| arg == "bench").is_some() {
+            parsed.command = Command::Bench;
+        } else if args.next_if(|arg| arg == "ingest").is_some() {
+            parsed.command = Command::Ingest;
+        } else if args.next_if(|arg| arg == "new-day").is_some() {
+            parsed.command = Command::NewDay;
+        }
+        while let Some(arg) = args.next() {
+            let mut value = |flag: &str| args.next().ok_or(format!("{} needs a value", flag));
+            match arg.as_str() {
+                "--part" | "-p" => {
+                    parsed.part = Some(match value(&arg)?.as_str() {
+                        "1" => 1,
+                        "2" => 2,
+                        other => return Err(format!("part '{}' is not 1 or 2", other)),
+                    })
+                }
+                "--input" | "-i" => parsed.input = Some(value(&arg)?.into()),
+                "--inputs" => parsed.inputs = value(&arg)?.into(),
+                "--check" => parsed.check = true,
+                "--record" => parsed.record = true,
+                "--reject" => parsed.reject = Some(value(&arg)?.parse()?),
+                "--answers" => parsed.answers = value(&arg)?.into(),
+                "--runs" => {
+                    parsed.runs = match value(&arg)?.parse() {
+                        Ok(0) | Err(_) => return Err("--runs needs a positive number".to_string()),
+                        Ok(runs) => runs,
+                    }
+                }
+                "--json" => parsed.json = Some(value(&arg)?.into()),
+                "--history" => parsed.history = value(&arg)?.into(),
+                "--no-save" => parsed.save = false,
+                "--description" => parsed.description = true,
+                "--log" => parsed.log = Some(value(&arg)?.parse()?),
+                "--svg" => parsed.svg = Some(value(&arg)?.into()),
+                "--viz" => parsed.viz = Some(value(&arg)?.parse()?),
+                "--fps" => {
+                    parsed.fps = match value(&arg)?.parse() {
+                        Ok(0) | Err(_) => return Err("--fps needs a positive number".to_string()),
+                        Ok(fps) => fps,
+                    }
+                }
+                days if !days.starts_with('-') => parsed.days = parse_days(days)?,
+                unknown => return Err(format!("unknown argument '{}'", unknown)),
+            }
+        }
+        if matches!(parsed.command, Command::NewDay) && parsed.days.start() != parsed.days.end() {
+            return Err("new-day needs a single day".to_string());
+        }
+        if parsed.input.is_some() && parsed.days.start() != parsed.days.end() {
+            return Err("--input can only be used with a single day".to_string());
+        }
+        if parsed.viz.is_some()
+            && (!matches!(parsed.command, Command::Run) || parsed.days.start() != parsed.days.end())
+        {
+            return Err("--viz can only be used when running a single day".to_string());
+        }
+        if parsed.reject.is_some()
+            && (parsed.days.start() != parsed.days.end() || parsed.part.is_none())
+        {
+            return Err("--reject can only be used with a single day and --part".to_string());
+        }
+        Ok(parsed)
+    }
+}
+
+/// Accepts `N`, `A..B` and `A..=B`.
+fn parse_days(days: &str) -> Result<RangeInclusive<u8>, String> {
+    let day = |s: &str| {
+        s.parse::<u8>()
+            .map_err(|e| format!("'{}' is not a day: {}", s, e))
+    };
+    let range = if let Some((start, end)) = days.split_once("..=") {
+        day(start)?..=day(end)?
+    } else if let Some((start, end)) = days.split_once("..") {
+        day(start)?..=day(end)?.saturating_sub(1)
+    } else {
+        day(days)?..=day(days)?
+    };
+    if range.is_empty() {
+        return Err(format!("'{}' contains no days", days));
+    }
+    Ok(range)
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(e) = util::trace::init(args.log.clone()) {
+        eprintln!("Could not set up logging: {}", e);
+        return ExitCode::FAILURE;
+    }
+    let registry = aoc2024::registry();
+    match args.command {
+        Command::Run => run(&args, &registry),
+        Command::Bench => bench(&args, &registry),
+        Command::Ingest => ingest(&args, &registry),
+        Command::NewDay => new_day(&args),
+    }
+}
+
+/// Finds the input of `day`, cleaned up as its solvers need,
+/// reporting why if it is missing.
+fn input(args: &Args, day: u8, solvers: &util::Day) -> Option<&'static str> {
+    eprintln!("\nDay {}", day);
+    let input = match DayInput::find(&args.inputs, day, args.input.as_deref()) {
+        Ok(input) => input,
+        Err(e) if e.kind() == io::ErrorKind::NotFound && args.input.is_none() => {
+            eprintln!("Not found, trying to fetch it.");
+            match Fetcher::from_env(2024, SESSION_FILE)
+                .and_then(|fetcher| DayInput::fetch(&args.inputs, day, &fetcher))
+            {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("No input for day {}: {}", day, e);
+                    return None;
+                }
+            }
+        }
+        Err(e) => {
+            eprintln!("No input for day {}: {}", day, e);
+            return None;
+        }
+    };
+    Some(input.normalize(solvers.normalize()).leak())
+}
+
+fn wanted_parts<'a>(args: &'a Args, solvers: &'a util::Day) -> impl Iterator<Item = u8> + 'a {
+    solvers
+        .parts()
+        .filter(|part| args.part.is_none_or(|p| p == *part))
+}
+
+fn run(args: &Args, registry: &Registry) -> ExitCode {
+    let mut answers = match Answers::load(&args.answers) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Could not read answers: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut status = ExitCode::SUCCESS;
+    let mut verdicts = Vec::new();
+    let mut progress = Progress::default();
+    if args.check {
+        println!("{:>3} {:>4}  {:<7}  answer", "day", "part", "status");
+    }
+    if let Some(dir) = &args.svg {
+        util::svg::export_to(dir);
+    }
+    for (day, solvers) in registry.select(args.days.clone()) {
+        let Some(input) = input(args, day, solvers) else {
+            status = ExitCode::FAILURE;
+            continue;
+        };
+        let parsed = match isolate(|| solvers.parse(input)) {
+            Ok(Ok(parsed)) => parsed,
+            Ok(Err(e)) => {
+                eprintln!("Could not parse input for day {}: {}", day, e);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+            Err(outcome) => {
+                eprintln!("Could not parse input for day {}: {}", day, outcome);
+                for _ in wanted_parts(args, solvers) {
+                    progress.add(&outcome);
+                }
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        if args.viz.is_some() {
+            viz::record(viz::CAPACITY);
+        }
+        for part in wanted_parts(args, solvers) {
+            let outcome = Outcome::of(|| solvers.solve(part, &parsed).expect("part is registered"));
+            progress.add(&outcome);
+            let answer = match outcome {
+                Outcome::Solved(answer) => answer,
+                unsolved => {
+                    if matches!(unsolved, Outcome::Failed(_)) {
+                        status = ExitCode::FAILURE;
+                    }
+                    if args.check {
+                        match &unsolved {
+                            Outcome::Failed(reason) => {
+                                println!("{:>3} {:>4}  {:<7}  {}", day, part, "failed", reason)
+                            }
+                            _ => println!("{:>3} {:>4}  unsolved", day, part),
+                        }
+                    } else {
+                        println!("Day {} part {}: {}", day, part, unsolved);
+                    }
+                    continue;
+                }
+            };
+            if args.check {
+                let verdict = answers.check(day, part, &answer);
+                match &verdict {
+                    Verdict::Fail { expected } => println!(
+                        "{:>3} {:>4}  {:<7}  {} (expected {})",
+                        day, part, verdict, answer, expected
+                    ),
+                    Verdict::Rejected(objection) => println!(
+                        "{:>3} {:>4}  {:<7}  {} ({})",
+                        day, part, verdict, answer, objection
+                    ),
+                    _ => println!("{:>3} {:>4}  {:<7}  {}", day, part, verdict, answer),
+                }
+                verdicts.push(verdict);
+            } else {
+                println!("Day {} part {}: {}", day, part, answer);
+                if let Some(objection) = answers.objection(day, part, &answer) {
+                    eprintln!("Warning: this answer {}, do not submit it", objection);
+                }
+            }
+            if let Some(hint) = args.reject {
+                answers.reject(day, part, hint, answer.clone());
+            }
+            if args.record {
+                match answers.record(day, part, answer.clone()) {
+                    Some(previous) if previous != answer => eprintln!(
+                        "Replaced recorded answer {} for day {} part {}",
+                        previous, day, part
+                    ),
+                    _ => {}
+                }
+            }
+        }
+    }
+    if let Some(output) = &args.viz {
+        match viz::finish() {
+            Some(recorder) if recorder.frames().is_empty() => {
+                eprintln!("No frames were recorded, this day has no visualization");
+            }
+            Some(recorder) => {
+                eprintln!(
+                    "Recorded {} frames (every {} step(s))",
+                    recorder.frames().len(),
+                    recorder.stride()
+                );
+                if let Err(e) = output.play(recorder.frames(), args.fps) {
+                    eprintln!("Could not play frames: {}", e);
+                    status = ExitCode::FAILURE;
+                }
+            }
+            None => {}
+        }
+    }
+    if args.svg.is_some() {
+        for path in util::svg::finish() {
+            eprintln!("Wrote {}", path.display());
+        }
+    }
+    if args.check {
+        let count = |wanted: fn(&Verdict) -> bool| verdicts.iter().filter(|v| wanted(v)).count();
+        let failed = count(|v| matches!(v, Verdict::Fail { .. } | Verdict::Rejected(_)));
+        println!(
+            "\n{} passed, {} failed, {} unknown",
+            count(|v| *v == Verdict::Pass),
+            failed,
+            count(|v| *v == Verdict::Unknown)
+        );
+        if failed > 0 {
+            status = ExitCode::FAILURE;
+        }
+    }
+    println!("\nProgress: {}", progress);
+    if args.record || args.reject.is_some() {
+        if let Err(e) = answers.save() {
+            eprintln!("Could not save answers to {:?}: {}", answers.path(), e);
+            status = ExitCode::FAILURE;
+        }
+    }
+    status
+}
+
+fn bench(args: &Args, registry: &Registry) -> ExitCode {
+    let mut status = ExitCode::SUCCESS;
+    let mut report = BenchReport(Vec::new());
+    for (day, solvers) in registry.select(args.days.clone()) {
+        let Some(input) = input(args, day, solvers) else {
+            status = ExitCode::FAILURE;
+            continue;
+        };
+        let parts = wanted_parts(args, solvers).collect::<Vec<_>>();
+        match util::bench_day(day, solvers, input, parts, args.runs) {
+            Ok(bench) => {
+                if bench
+                    .parts
+                    .iter()
+                    .any(|(_, stats)| matches!(stats, Err(Outcome::Failed(_))))
+                {
+                    status = ExitCode::FAILURE;
+                }
+                report.0.push(bench)
+            }
+            Err(outcome) => {
+                eprintln!("Could not parse input for day {}: {}", day, outcome);
+                if !matches!(outcome, Outcome::Unsolved) {
+                    status = ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+    print!("\n{}", report);
+    if let Some(path) = &args.json {
+        if let Err(e) = std::fs::write(path, report.json()) {
+            eprintln!("Could not write JSON report to {:?}: {}", path, e);
+            status = ExitCode::FAILURE;
+        }
+    }
+    let mut history = match BenchHistory::load(&args.history) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Could not read benchmark history: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let origin = Origin::current();
+    let comparisons = history.compare(&origin, &report);
+    if comparisons.is_empty() {
+        println!(
+            "\nNo earlier {} benchmarks on {} to compare against",
+            origin.profile, origin.machine
+        );
+    } else {
+        println!(
+            "\nMean against the last {} benchmarks on {} (now at {}):",
+            origin.profile, origin.machine, origin.commit
+        );
+        for comparison in &comparisons {
+            let flag = if comparison.is_slowdown() {
+                "  SLOWER"
+            } else {
+                ""
+            };
+            println!("{}{}", comparison, flag);
+        }
+        let slower = comparisons.iter().filter(|c| c.is_slowdown()).count();
+        println!(
+            "{} of {} phases significantly slower",
+            slower,
+            comparisons.len()
+        );
+    }
+    if args.save {
+        if let Err(e) = history.append(&origin, &report) {
+            eprintln!(
+                "Could not save benchmark history to {:?}: {}",
+                history.path(),
+                e
+            );
+            status = ExitCode::FAILURE;
+        }
+    }
+    status
+}
+
+fn ingest(args: &Args, registry: &Registry) -> ExitCode {
+    let mut status = ExitCode::SUCCESS;
+    for day in args.days.clone() {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("day{}", day));
+        match util::puzzle::ingest(&dir, day, registry) {
+            Ok(examples) if examples.is_empty() => {}
+            Ok(examples) => {
+                for example in examples {
+                    print!(
+                        "Day {} part {}: {} should give {}",
+                        day, example.part, example.fixture, example.answer
+                    );
+                    match example.ignored {
+                        Some(reason) => println!(", ignored ({})", reason),
+                        None => println!(),
+                    }
+                }
+            }
+            Err(e) => {
+                eprintln!("Could not ingest day {}: {}", day, e);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
+
+fn new_day(args: &Args) -> ExitCode {
+    let day = *args.days.start();
+    let dir = match util::scaffold::new_day(env!("CARGO_MANIFEST_DIR"), day) {
+        Ok(dir) => dir,
+        Err(e) => {
+            eprintln!("Could not create day {}: {}", day, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("Created {}", dir.display());
+    if args.description {
+        let html = Fetcher::from_env(2024, SESSION_FILE)
+            .and_then(|fetcher| fetcher.fetch_description(day, SystemTime::now()));
+        let written = match html {
+            Ok(html) => {
+                let descriptions = util::puzzle::markdown_from_html(&html, 2024, day);
+                util::scaffold::write_descriptions(&dir, &descriptions)
+                    .map(|()| descriptions.len())
+                    .map_err(|e| e.to_string())
+            }
+            Err(e) => Err(e.to_string()),
+        };
+        match written {
+            Ok(0) => eprintln!("Found no puzzle description on the page of day {}", day),
+            Ok(parts) => println!("Wrote the description of {} part(s)", parts),
+            Err(e) => {
+                eprintln!("Could not get the description of day {}: {}", day, e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_days;
+
+    #[test]
+    fn day_ranges_parse() {
+        assert_eq!(parse_days("6"), Ok(6..=6));
+        assert_eq!(parse_days("1..=12"), Ok(1..=12));
+        assert_eq!(parse_days("1..13"), Ok(1..=12));
+        assert!(parse_days("3..1").is_err());
+        assert!(parse_days("six").is_err());
+    }
+}
@@ -0,0 +1,237 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+    io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// Answers submitted to the site, keyed by day and part.
+/// Stored one per line as `<day> <part> <kind> <answer>`, with `#` comments,
+/// where `kind` is `correct` or the hint a rejection came with.
+pub struct Answers {
+    path: PathBuf,
+    correct: BTreeMap<(u8, u8), String>,
+    rejected: BTreeMap<(u8, u8), Vec<(Hint, String)>>,
+}
+impl Answers {
+    /// Reads the answers file at `path`. A missing file has no answers yet.
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let mut answers = Answers {
+            path,
+            correct: BTreeMap::new(),
+            rejected: BTreeMap::new(),
+        };
+        let contents = match std::fs::read_to_string(&answers.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(answers),
+            Err(e) => return Err(e),
+        };
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = |reason: &str| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}:{}: {}", answers.path.display(), i + 1, reason),
+                )
+            };
+            let mut fields = line.splitn(4, ' ');
+            let mut number = |name: &str| {
+                fields
+                    .next()
+                    .and_then(|field| field.parse::<u8>().ok())
+                    .ok_or_else(|| invalid(&format!("expected a {} number", name)))
+            };
+            let (day, part) = (number("day")?, number("part")?);
+            match (fields.next(), fields.next()) {
+                (Some("correct"), Some(answer)) => {
+                    answers.correct.insert((day, part), answer.to_string());
+                }
+                (Some(kind), Some(answer)) => {
+                    let hint = kind.parse::<Hint>().map_err(|e| invalid(&e))?;
+                    answers.reject(day, part, hint, answer);
+                }
+                _ => return Err(invalid("expected `<day> <part> <kind> <answer>`")),
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn correct(&self, day: u8, part: u8) -> Option<&str> {
+        self.correct.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn rejected(&self, day: u8, part: u8) -> &[(Hint, String)] {
+        self.rejected.get(&(day, part)).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Verdict {
+        match self.correct(day, part) {
+            Some(correct) if correct == answer => Verdict::Pass,
+            Some(correct) => Verdict::Fail {
+                expected: correct.to_string(),
+            },
+            None => match self.objection(day, part, answer) {
+                Some(objection) => Verdict::Rejected(objection),
+                None => Verdict::Unknown,
+            },
+        }
+    }
+
+    /// Why the site would reject `answer` again, judging by earlier rejections.
+    /// Bounds from `too-high`/`too-low` hints only apply to numeric answers.
+    pub fn objection(&self, day: u8, part: u8, answer: &str) -> Option<Objection> {
+        let rejected = self.rejected(day, part);
+        if let Some((hint, _)) = rejected.iter().find(|(_, rejected)| rejected == answer) {
+            return Some(Objection::Repeated(*hint));
+        }
+        let answer = answer.parse::<i128>().ok()?;
+        let bound = |wanted: Hint| {
+            rejected
+                .iter()
+                .filter(move |(hint, _)| *hint == wanted)
+                .filter_map(|(_, rejected)| rejected.parse::<i128>().ok())
+        };
+        if let Some(high) = bound(Hint::TooHigh).filter(|high| answer > *high).min() {
+            return Some(Objection::AboveTooHigh(high));
+        }
+        if let Some(low) = bound(Hint::TooLow).filter(|low| answer < *low).max() {
+            return Some(Objection::BelowTooLow(low));
+        }
+        None
+    }
+
+    /// Remembers `answer` as verified, giving back the one it replaced.
+    pub fn record(&mut self, day: u8, part: u8, answer: impl Into<String>) -> Option<String> {
+        self.correct.insert((day, part), answer.into())
+    }
+
+    /// Remembers that the site rejected `answer`, and what it hinted at.
+    pub fn reject(&mut self, day: u8, part: u8, hint: Hint, answer: impl Into<String>) {
+        let answer = answer.into();
+        let rejected = self.rejected.entry((day, part)).or_default();
+        if !rejected.iter().any(|(_, known)| *known == answer) {
+            rejected.push((hint, answer));
+        }
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let mut contents =
+            String::from("# <day> <part> <correct|too-high|too-low|wrong> <answer>\n");
+        let keys = self.correct.keys().chain(self.rejected.keys());
+        let mut keys = keys.copied().collect::<Vec<_>>();
+        keys.sort();
+        keys.dedup();
+        for (day, part) in keys {
+            if let Some(answer) = self.correct(day, part) {
+                contents.push_str(&format!("{} {} correct {}\n", day, part, answer));
+            }
+            for (hint, answer) in self.rejected(day, part) {
+                contents.push_str(&format!("{} {} {} {}\n", day, part, hint, answer));
+            }
+        }
+        std::fs::write(&self.path, contents)
+    }
+}
+
+/// What the site said about a rejected answer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+impl FromStr for Hint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "too-high" => Ok(Hint::TooHigh),
+            "too-low" => Ok(Hint::TooLow),
+            "wrong" => Ok(Hint::Wrong),
+            other => Err(format!(
+                "unknown hint '{}', expected too-high, too-low or wrong",
+                other
+            )),
+        }
+    }
+}
+impl Display for Hint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Hint::TooHigh => f.pad("too-high"),
+            Hint::TooLow => f.pad("too-low"),
+            Hint::Wrong => f.pad("wrong"),
+        }
+    }
+}
+
+/// Why an answer is known to be wrong without submitting it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Objection {
+    Repeated(Hint),
+    AboveTooHigh(i128),
+    BelowTooLow(i128),
+}
+impl Display for Objection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Objection::Repeated(hint) => write!(f, "was already rejected ({})", hint),
+            Objection::AboveTooHigh(high) => write!(f, "is above {}, which was too high", high),
+            Objection::BelowTooLow(low) => write!(f, "is below {}, which was too low", low),
+        }
+    }
+}
+
+/// How an answer compares to the recorded ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Rejected(Objection),
+    Unknown,
+}
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => f.pad("pass"),
+            Verdict::Fail { .. } => f.pad("FAIL"),
+            Verdict::Rejected(_) => f.pad("WRONG"),
+            Verdict::Unknown => f.pad("unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejections_bound_answers() {
+        let mut answers = Answers::load("no such answers file").unwrap();
+        answers.reject(11, 2, Hint::TooLow, "613318790");
+        answers.reject(11, 2, Hint::TooHigh, "900000000000000");
+        assert_eq!(
+            answers.objection(11, 2, "613318790"),
+            Some(Objection::Repeated(Hint::TooLow))
+        );
+        assert_eq!(
+            answers.objection(11, 2, "1000"),
+            Some(Objection::BelowTooLow(613318790))
+        );
+        assert_eq!(
+            answers.objection(11, 2, "999999999999999"),
+            Some(Objection::AboveTooHigh(900000000000000))
+        );
+        assert_eq!(answers.objection(11, 2, "221683913164898"), None);
+        assert_eq!(answers.check(11, 2, "221683913164898"), Verdict::Unknown);
+    }
+}
@@ -0,0 +1,234 @@
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{isolate, Day, Outcome, ParseError};
+
+/// A timed step of a day: its parse, or one of its parts.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Part(u8),
+}
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => f.pad("parse"),
+            Phase::Part(part) => f.pad(&format!("p{}", part)),
+        }
+    }
+}
+impl FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix('p').and_then(|part| part.parse().ok()) {
+            _ if s == "parse" => Ok(Phase::Parse),
+            Some(part) => Ok(Phase::Part(part)),
+            None => Err(format!("unknown phase '{}'", s)),
+        }
+    }
+}
+
+/// Summary of repeated timings of a single phase.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    /// Sample standard deviation, zero for a single run.
+    pub std_dev: Duration,
+}
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "stats need at least one sample");
+        samples.sort();
+        let runs = samples.len();
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / runs as f64;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / (runs.max(2) - 1) as f64;
+        Stats {
+            runs,
+            min: samples[0],
+            median: samples[runs / 2],
+            max: samples[runs - 1],
+            mean: Duration::from_secs_f64(mean),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    fn json(&self) -> String {
+        format!(
+            r#"{{"runs": {}, "min_ns": {}, "median_ns": {}, "max_ns": {}, "mean_ns": {}, "std_dev_ns": {}}}"#,
+            self.runs,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.max.as_nanos(),
+            self.mean.as_nanos(),
+            self.std_dev.as_nanos()
+        )
+    }
+}
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:>12} {:>12} {:>12}",
+            format!("{:.2?}", self.min),
+            format!("{:.2?}", self.median),
+            format!("{:.2?}", self.max)
+        )
+    }
+}
+
+/// Timings of one day: its parse, and every part it was asked to solve.
+/// Parts that panicked have how they went instead of timings.
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Stats,
+    pub parts: Vec<(u8, Result<Stats, Outcome>)>,
+}
+impl DayBench {
+    /// The timed phases, leaving out parts that panicked.
+    pub fn phases(&self) -> impl Iterator<Item = (Phase, &Stats)> {
+        let parts = self
+            .parts
+            .iter()
+            .filter_map(|(part, stats)| Some((Phase::Part(*part), stats.as_ref().ok()?)));
+        std::iter::once((Phase::Parse, &self.parse)).chain(parts)
+    }
+}
+
+/// Times `runs` parses of `input`, then `runs` solves of each of `parts`.
+/// Parts reuse the last parsed model, such that they are timed on their own.
+/// A part that panics is not timed further (see [`isolate`]), and the
+/// outcome of a parse that fails or panics is all there is of the day.
+pub fn bench_day(
+    day: u8,
+    solvers: &Day,
+    input: &'static str,
+    parts: impl IntoIterator<Item = u8>,
+    runs: usize,
+) -> Result<DayBench, Outcome> {
+    let (samples, parsed) = isolate(|| {
+        let mut samples = Vec::with_capacity(runs);
+        let mut parsed = None;
+        for _ in 0..runs.max(1) {
+            let start = Instant::now();
+            let model = std::hint::black_box(solvers.parse(input)?);
+            samples.push(start.elapsed());
+            parsed = Some(model);
+        }
+        Ok((samples, parsed.expect("parsed at least once")))
+    })?
+    .map_err(|e: ParseError| Outcome::Failed(e.to_string()))?;
+    let parse = Stats::from_samples(samples);
+    let parts = parts
+        .into_iter()
+        .map(|part| {
+            let samples = isolate(|| {
+                (0..runs.max(1))
+                    .map(|_| {
+                        let start = Instant::now();
+                        std::hint::black_box(solvers.solve(part, &parsed));
+                        start.elapsed()
+                    })
+                    .collect()
+            });
+            (part, samples.map(Stats::from_samples))
+        })
+        .collect();
+    Ok(DayBench { day, parse, parts })
+}
+
+/// Every benchmarked day, printable as a table or as JSON.
+pub struct BenchReport(pub Vec<DayBench>);
+impl BenchReport {
+    pub fn json(&self) -> String {
+        let days = self
+            .0
+            .iter()
+            .map(|bench| {
+                let parts = bench
+                    .parts
+                    .iter()
+                    .map(|(part, stats)| match stats {
+                        Ok(stats) => format!(r#""{}": {}"#, part, stats.json()),
+                        // the reason of a failure may need escaping, so it is left out
+                        Err(Outcome::Failed(_)) => {
+                            format!(r#""{}": {{"outcome": "failed"}}"#, part)
+                        }
+                        Err(_) => format!(r#""{}": {{"outcome": "unsolved"}}"#, part),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!(
+                    r#"    {{"day": {}, "parse": {}, "parts": {{{}}}}}"#,
+                    bench.day,
+                    bench.parse.json(),
+                    parts
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",\n");
+        format!("{{\"days\": [\n{}\n]}}\n", days)
+    }
+}
+impl Display for BenchReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:>3} {:>5}  {:>12} {:>12} {:>12}",
+            "day", "phase", "min", "median", "max"
+        )?;
+        for bench in &self.0 {
+            writeln!(f, "{:>3} {:>5}  {}", bench.day, Phase::Parse, bench.parse)?;
+            for (part, stats) in &bench.parts {
+                match stats {
+                    Ok(stats) => {
+                        writeln!(f, "{:>3} {:>5}  {}", bench.day, Phase::Part(*part), stats)?
+                    }
+                    Err(outcome) => {
+                        writeln!(f, "{:>3} {:>5}  {}", bench.day, Phase::Part(*part), outcome)?
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{bench_day, Stats};
+    use crate::{Outcome, Registry};
+
+    #[test]
+    fn stats_are_order_independent() {
+        let stats = Stats::from_samples([5, 1, 3].map(Duration::from_millis).to_vec());
+        assert_eq!(stats.runs, 3);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn panicking_parts_are_not_timed() {
+        let mut registry = Registry::new();
+        registry.register(1, 1, |input| input.len());
+        registry.register(1, 2, |_| -> usize { todo!() });
+        let bench = bench_day(1, registry.get(1).unwrap(), "input", [1, 2], 3).unwrap();
+        assert_eq!(bench.parts[0].1.as_ref().map(|stats| stats.runs), Ok(3));
+        assert_eq!(bench.parts[1].1.as_ref().err(), Some(&Outcome::Unsolved));
+        assert_eq!(bench.phases().count(), 2);
+    }
+}
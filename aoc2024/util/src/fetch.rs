@@ -0,0 +1,247 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// The site asks automated requests to say where they come from.
+const USER_AGENT: &str = "github.com/mikkelens/advent-of-code input fetcher (ureq)";
+
+/// Downloads puzzle inputs with the user's session cookie,
+/// keeping them in an `inputs` folder such that every input is fetched once.
+pub struct Fetcher {
+    base_url: String,
+    year: u16,
+    session: String,
+    agent: ureq::Agent,
+}
+impl Fetcher {
+    pub fn new(year: u16, session: impl Into<String>) -> Self {
+        Fetcher {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            year,
+            session: session.into(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Takes the session from `AOC_SESSION`, or else from the `session_file`,
+    /// and the base URL from `AOC_BASE_URL` if set.
+    pub fn from_env(year: u16, session_file: impl AsRef<Path>) -> Result<Self, FetchError> {
+        let session = match std::env::var("AOC_SESSION") {
+            Ok(session) => session,
+            Err(_) => std::fs::read_to_string(session_file.as_ref())
+                .map_err(|_| FetchError::NoSession(session_file.as_ref().to_path_buf()))?,
+        };
+        let session = session.trim();
+        if session.is_empty() {
+            return Err(FetchError::NoSession(session_file.as_ref().to_path_buf()));
+        }
+        let fetcher = Fetcher::new(year, session);
+        Ok(match std::env::var("AOC_BASE_URL") {
+            Ok(base_url) => fetcher.with_base_url(base_url),
+            Err(_) => fetcher,
+        })
+    }
+
+    /// Points the fetcher at another server, like a local stand-in for tests.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Puzzles unlock at midnight in UTC-5, every day of December until the 25th.
+    pub fn unlocks_at(&self, day: u8) -> SystemTime {
+        let days = days_since_epoch(self.year as i64, 12, day as i64);
+        UNIX_EPOCH + Duration::from_secs(days as u64 * 24 * 60 * 60 + 5 * 60 * 60)
+    }
+
+    /// The input of `day`, from `inputs` if it was fetched before.
+    pub fn cached(&self, inputs: impl AsRef<Path>, day: u8) -> Result<String, FetchError> {
+        let path = inputs.as_ref().join(day.to_string());
+        match std::fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(FetchError::Io(e)),
+        }
+        let input = self.fetch(day, SystemTime::now())?;
+        std::fs::create_dir_all(inputs.as_ref()).map_err(FetchError::Io)?;
+        std::fs::write(&path, &input).map_err(FetchError::Io)?;
+        Ok(input)
+    }
+
+    /// Downloads the input of `day`, unless it is not unlocked by `now`.
+    pub fn fetch(&self, day: u8, now: SystemTime) -> Result<String, FetchError> {
+        self.get(day, now, "/input")
+    }
+
+    /// Downloads the puzzle page of `day` as HTML, including part two
+    /// once part one is solved by the session's user.
+    pub fn fetch_description(&self, day: u8, now: SystemTime) -> Result<String, FetchError> {
+        self.get(day, now, "")
+    }
+
+    fn get(&self, day: u8, now: SystemTime, page: &str) -> Result<String, FetchError> {
+        if !(1..=25).contains(&day) {
+            return Err(FetchError::NoSuchDay(day));
+        }
+        if let Ok(wait) = self.unlocks_at(day).duration_since(now) {
+            return Err(FetchError::Locked { day, wait });
+        }
+        let url = format!("{}/{}/day/{}{}", self.base_url, self.year, day, page);
+        eprint!("Fetching {}... ", url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        match response {
+            Ok(response) => {
+                let body = response.into_string().map_err(FetchError::Io)?;
+                eprintln!("Fetched!");
+                Ok(body)
+            }
+            Err(ureq::Error::Status(status, response)) => {
+                eprintln!();
+                let body = response.into_string().unwrap_or_default();
+                Err(FetchError::Status(status, body.trim().to_string()))
+            }
+            Err(e) => {
+                eprintln!();
+                Err(FetchError::Transport(e.to_string()))
+            }
+        }
+    }
+}
+
+/// Days from 1970-01-01 to the given date (Howard Hinnant's `days_from_civil`).
+fn days_since_epoch(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    NoSession(PathBuf),
+    NoSuchDay(u8),
+    Locked { day: u8, wait: Duration },
+    Status(u16, String),
+    Transport(String),
+    Io(io::Error),
+}
+impl Display for FetchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::NoSession(path) => write!(
+                f,
+                "no session cookie, set AOC_SESSION or put it in {:?}",
+                path
+            ),
+            FetchError::NoSuchDay(day) => write!(f, "there is no day {}", day),
+            FetchError::Locked { day, wait } => write!(
+                f,
+                "day {} unlocks in {}h{:02}m, not fetching it yet",
+                day,
+                wait.as_secs() / 3600,
+                wait.as_secs() / 60 % 60
+            ),
+            FetchError::Status(400, _) => write!(f, "the session cookie was not accepted"),
+            FetchError::Status(status, body) => write!(f, "site answered {}: {}", status, body),
+            FetchError::Transport(e) => write!(f, "could not reach the site: {}", e),
+            FetchError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+impl Error for FetchError {}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    /// Answers a single request with `status` and `body`, giving back the request head.
+    fn stand_in(status: &str, body: &'static str) -> (String, std::thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let status = status.to_string();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let head = BufReader::new(&stream)
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n");
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            head
+        });
+        (url, server)
+    }
+
+    #[test]
+    fn days_unlock_at_midnight_eastern() {
+        let fetcher = Fetcher::new(2024, "cookie");
+        let unlock = fetcher.unlocks_at(1).duration_since(UNIX_EPOCH).unwrap();
+        // 2024-12-01T05:00:00Z
+        assert_eq!(unlock.as_secs(), 1733029200);
+        let before = fetcher.unlocks_at(1) - Duration::from_secs(90 * 60);
+        assert!(matches!(
+            fetcher.fetch(1, before),
+            Err(FetchError::Locked { day: 1, .. })
+        ));
+        assert!(matches!(
+            fetcher.fetch(26, SystemTime::now()),
+            Err(FetchError::NoSuchDay(26))
+        ));
+    }
+
+    #[test]
+    fn inputs_are_fetched_once_with_cookie() {
+        let (url, server) = stand_in("200 OK", "1 2\n3 4\n");
+        let fetcher = Fetcher::new(2024, "cookie").with_base_url(url);
+        let inputs = std::env::temp_dir().join(format!("aoc-fetch-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&inputs);
+        assert_eq!(fetcher.cached(&inputs, 3).unwrap(), "1 2\n3 4\n");
+        let head = server.join().unwrap();
+        assert!(
+            head.starts_with("GET /2024/day/3/input HTTP/1.1"),
+            "{}",
+            head
+        );
+        assert!(head.contains("Cookie: session=cookie"), "{}", head);
+        assert!(head.contains(USER_AGENT), "{}", head);
+        // the stand-in is gone, so this must come from the cache
+        assert_eq!(fetcher.cached(&inputs, 3).unwrap(), "1 2\n3 4\n");
+        std::fs::remove_dir_all(&inputs).unwrap();
+    }
+
+    #[test]
+    fn rejections_are_reported() {
+        let (url, server) = stand_in("400 Bad Request", "Puzzle inputs differ by user.");
+        let fetcher = Fetcher::new(2024, "stale").with_base_url(url);
+        let error = fetcher.fetch(3, SystemTime::now()).unwrap_err();
+        server.join().unwrap();
+        assert_eq!(error.to_string(), "the session cookie was not accepted");
+    }
+}
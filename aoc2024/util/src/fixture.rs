@@ -0,0 +1,73 @@
+//! Real puzzle inputs for tests. The inputs are private to each user,
+//! so a checkout without them should still build and pass its tests.
+
+use std::{
+    io::{self, Write},
+    path::Path,
+};
+
+use crate::DayInput;
+
+/// The input of `day` in `inputs`, read when the test runs.
+/// `None` (with a note on why the test is skipped) if it is missing or empty.
+pub fn real_input(inputs: impl AsRef<Path>, day: u8) -> Option<&'static str> {
+    let path = inputs.as_ref().join(day.to_string());
+    let reason = match DayInput::find(inputs.as_ref(), day, None) {
+        Ok(input) if !input.as_ref().trim().is_empty() => return Some(input.leak()),
+        Ok(_) => "it is empty".to_string(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => "it is not checked out".to_string(),
+        Err(e) => e.to_string(),
+    };
+    // straight to stderr, since the test harness only shows printed output of failing tests
+    let _ = writeln!(
+        io::stderr(),
+        "skipped: no input for day {} at {} ({})",
+        day,
+        path.display(),
+        reason
+    );
+    None
+}
+
+/// The real input of `day` from the `inputs` folder next to the calling day crate,
+/// or else returns from the test, skipping it.
+/// ```ignore
+/// #[test]
+/// fn input_solvable() {
+///     assert_eq!(super::solve(util::input_or_skip!(7)), 4364915411363);
+/// }
+/// ```
+#[macro_export]
+macro_rules! input_or_skip {
+    ($day:expr) => {
+        match $crate::fixture::real_input(concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs"), $day) {
+            Some(input) => input,
+            None => return,
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_inputs_skip() {
+        let inputs = std::env::temp_dir().join(format!("aoc-fixture-test-{}", std::process::id()));
+        std::fs::create_dir_all(&inputs).unwrap();
+        std::fs::write(inputs.join("1"), "1 2\n").unwrap();
+        std::fs::write(inputs.join("2"), "").unwrap();
+        assert_eq!(real_input(&inputs, 1), Some("1 2\n"));
+        assert_eq!(real_input(&inputs, 2), None);
+        assert_eq!(real_input(&inputs, 3), None);
+        std::fs::remove_dir_all(&inputs).unwrap();
+
+        let mut reached = false;
+        let mut test = || {
+            let _input = input_or_skip!(26);
+            reached = true;
+        };
+        test();
+        assert!(!reached);
+    }
+}
@@ -0,0 +1,335 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::ParseError;
+
+/// A cell position, with `x` growing rightwards and `y` downwards.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub struct Coord {
+    pub x: usize,
+    pub y: usize,
+}
+impl Coord {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Coord { x, y }
+    }
+
+    /// The position `step` away, unless that is left of or above the origin.
+    pub fn offset(self, (dx, dy): (isize, isize)) -> Option<Coord> {
+        Some(Coord {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+}
+impl From<(usize, usize)> for Coord {
+    fn from((x, y): (usize, usize)) -> Self {
+        Coord { x, y }
+    }
+}
+impl Display for Coord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}x,{}y)", self.x, self.y)
+    }
+}
+
+/// Steps to the 4 orthogonal neighbours: up, right, down, left.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+/// Steps to the 8 surrounding neighbours, clockwise from up.
+pub const SURROUNDING: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangle of cells, stored row by row.
+/// Every neighbour or step is bounds checked on both axes,
+/// such that moving off one side never wraps around to the next row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+}
+impl<T> Grid<T> {
+    /// Rows of `width` cells. `None` if the cells do not fill whole rows.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Option<Self> {
+        (width > 0 && cells.len().is_multiple_of(width)).then_some(Grid { cells, width })
+    }
+
+    /// `height` rows of `width` copies of `cell`. `None` if `width` is 0.
+    pub fn filled(width: usize, height: usize, cell: T) -> Option<Self>
+    where
+        T: Clone,
+    {
+        Grid::from_cells(width, vec![cell; width * height])
+    }
+
+    /// Parses a character map of equally long lines, one character per cell.
+    /// `cell` gets every character with its position, and rejects it with `None`.
+    pub fn parse_with(
+        input: &str,
+        mut cell: impl FnMut(Coord, char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        for (y, line) in input.trim_end_matches(['\r', '\n']).lines().enumerate() {
+            let line_start = line.as_ptr() as usize - input.as_ptr() as usize;
+            let mut x = 0;
+            for (i, c) in line.char_indices() {
+                match cell(Coord { x, y }, c) {
+                    Some(parsed) => cells.push(parsed),
+                    None => {
+                        let message = format_args!("unexpected {:?}", c);
+                        return Err(ParseError::at(input, line_start + i, message));
+                    }
+                }
+                x += 1;
+            }
+            match width {
+                None if x == 0 => return Err(ParseError::new("grid starts with an empty line")),
+                None => width = Some(x),
+                Some(width) if width != x => {
+                    // At the first extra cell, or past the end of a short line.
+                    let end = line
+                        .char_indices()
+                        .nth(width)
+                        .map_or(line.len(), |(i, _)| i);
+                    let message = format_args!("{} cells wide, but the grid is {} wide", x, width);
+                    return Err(ParseError::at(input, line_start + end, message));
+                }
+                Some(_) => {}
+            }
+        }
+        let width = width.ok_or_else(|| ParseError::new("grid is empty"))?;
+        Ok(Grid { cells, width })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len() / self.width
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        coord.x < self.width && coord.y < self.height()
+    }
+
+    /// Index of `coord` in the row by row cells.
+    pub fn index_of(&self, coord: Coord) -> Option<usize> {
+        self.contains(coord).then(|| coord.y * self.width + coord.x)
+    }
+
+    /// Position of the cell at `index` in the row by row cells.
+    pub fn coord_of(&self, index: usize) -> Option<Coord> {
+        (index < self.cells.len()).then(|| Coord {
+            x: index % self.width,
+            y: index / self.width,
+        })
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.index_of(coord).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.index_of(coord).map(|index| &mut self.cells[index])
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        let width = self.width;
+        self.cells.iter().enumerate().map(move |(index, cell)| {
+            let coord = Coord {
+                x: index % width,
+                y: index / width,
+            };
+            (coord, cell)
+        })
+    }
+
+    /// Positions of every cell matching `wanted`.
+    pub fn positions<'g>(
+        &'g self,
+        mut wanted: impl FnMut(&T) -> bool + 'g,
+    ) -> impl Iterator<Item = Coord> + 'g {
+        self.iter()
+            .filter_map(move |(coord, cell)| wanted(cell).then_some(coord))
+    }
+
+    /// The position `step` away from `coord`, if it is on the grid.
+    pub fn step(&self, coord: Coord, step: (isize, isize)) -> Option<Coord> {
+        coord.offset(step).filter(|next| self.contains(*next))
+    }
+
+    /// The up to 4 orthogonal neighbours of `coord` that are on the grid.
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |step| self.step(coord, step))
+    }
+
+    /// The up to 8 surrounding neighbours of `coord` that are on the grid.
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        SURROUNDING
+            .into_iter()
+            .filter_map(move |step| self.step(coord, step))
+    }
+
+    /// Cells from `from` (inclusive), repeatedly taking `step` until leaving the grid.
+    pub fn ray(&self, from: Coord, step: (isize, isize)) -> impl Iterator<Item = (Coord, &T)> {
+        let first = self.contains(from).then_some(from);
+        std::iter::successors(first, move |coord| self.step(*coord, step))
+            .map(|coord| (coord, &self[coord]))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height()).then(|| &self.cells[y * self.width..][..self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = if x < self.width {
+            &self.cells[x..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every diagonal going down and to the right, starting from the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height())
+            .rev()
+            .map(|y| Coord { x: 0, y })
+            .chain((1..self.width).map(|x| Coord { x, y: 0 }));
+        starts.map(|start| self.ray(start, (1, 1)).map(|(_, cell)| cell))
+    }
+
+    /// Every diagonal going down and to the left, starting from the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let right = self.width - 1;
+        let starts = (0..self.width)
+            .map(|x| Coord { x, y: 0 })
+            .chain((1..self.height()).map(move |y| Coord { x: right, y }));
+        starts.map(|start| self.ray(start, (-1, 1)).map(|(_, cell)| cell))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+        }
+    }
+}
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &Self::Output {
+        self.get(coord)
+            .unwrap_or_else(|| panic!("{} is outside of the grid", coord))
+    }
+}
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        let index = self
+            .index_of(coord)
+            .unwrap_or_else(|| panic!("{} is outside of the grid", coord));
+        &mut self.cells[index]
+    }
+}
+/// Cells that convert from their character, like `char` itself.
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, |_, c| T::try_from(c).ok())
+    }
+}
+/// One line per row, without a trailing newline.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y != 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "#..\n.#.\n..#\n.##\n";
+
+    #[test]
+    fn grids_parse_and_render() {
+        let grid = MAP.parse::<Grid<char>>().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid[Coord::new(1, 3)], '#');
+        assert_eq!(grid.to_string(), MAP.trim_end());
+        assert!("##\n#\n".parse::<Grid<char>>().is_err());
+        let error = Grid::parse_with("##\r\n#x\r\n", |_, c| (c == '#').then_some(c));
+        let expected = "at line 2, column 2: unexpected 'x'\n  |\n2 | #x\n  |  ^";
+        assert_eq!(error.unwrap_err().to_string(), expected);
+        let filled = Grid::filled(2, 3, '.').unwrap();
+        assert_eq!((filled.width(), filled.height()), (2, 3));
+        assert_eq!(Grid::filled(0, 3, '.'), None);
+    }
+
+    #[test]
+    fn neighbours_do_not_wrap() {
+        let grid = MAP.parse::<Grid<char>>().unwrap();
+        let right_edge = Coord::new(2, 1);
+        assert_eq!(grid.step(right_edge, (1, 0)), None);
+        let neighbours = grid.neighbours4(right_edge).collect::<Vec<_>>();
+        assert_eq!(neighbours, [(2, 0), (2, 2), (1, 1)].map(Coord::from));
+        assert_eq!(grid.neighbours8(Coord::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Coord::new(1, 1)).count(), 8);
+        assert_eq!(grid.coord_of(5), Some(Coord::new(2, 1)));
+        assert_eq!(grid.index_of(Coord::new(3, 0)), None);
+    }
+
+    #[test]
+    fn views_follow_lines() {
+        let grid = MAP.parse::<Grid<char>>().unwrap();
+        let text = |cells: &mut dyn Iterator<Item = &char>| cells.collect::<String>();
+        assert_eq!(grid.row(3), Some(&['.', '#', '#'][..]));
+        assert_eq!(text(&mut grid.column(2)), "..##");
+        let diagonals = grid
+            .diagonals()
+            .map(|mut d| text(&mut d))
+            .collect::<Vec<_>>();
+        assert_eq!(diagonals, [".", ".#", "..#", "###", "..", "."]);
+        let anti = grid
+            .anti_diagonals()
+            .map(|mut d| text(&mut d))
+            .collect::<Vec<_>>();
+        assert_eq!(anti, ["#", "..", ".#.", "...", "##", "#"]);
+    }
+}
@@ -0,0 +1,360 @@
+use std::{
+    fmt::{Display, Formatter},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{BenchReport, Phase, Stats};
+
+/// Smallest relative slowdown of the mean worth flagging, such that tiny but
+/// consistent differences (like a changed inlining decision) are not reported.
+const MIN_SLOWDOWN: f64 = 0.05;
+/// Welch's t-statistic the slowdown must exceed. Deliberately strict,
+/// since runs on a busy machine are rarely independent.
+const MIN_T: f64 = 3.0;
+
+/// Where a benchmark was taken: the checked out commit, the machine and the
+/// build profile. Timings only compare well between runs with the same build
+/// settings, so baselines are only taken from the same profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Origin {
+    pub commit: String,
+    pub machine: String,
+    /// `debug` or `release`, by whether debug assertions are on.
+    pub profile: String,
+}
+impl Origin {
+    /// The current commit (with a `-dirty` suffix for uncommitted changes)
+    /// and host name, or `unknown` where they cannot be found out, along with
+    /// the profile this was built with.
+    pub fn current() -> Self {
+        let output = |program: &str, args: &[&str]| {
+            Command::new(program)
+                .args(args)
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        };
+        let commit = match output("git", &["rev-parse", "--short", "HEAD"]) {
+            Some(commit) => match output("git", &["status", "--porcelain"]) {
+                Some(changes) if !changes.is_empty() => format!("{}-dirty", commit),
+                _ => commit,
+            },
+            None => "unknown".to_string(),
+        };
+        let machine = std::env::var("AOC_MACHINE")
+            .ok()
+            .or_else(|| output("hostname", &[]))
+            .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+            .map(|name| name.trim().replace(char::is_whitespace, "-"))
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| "unknown".to_string());
+        let profile = if cfg!(debug_assertions) {
+            "debug"
+        } else {
+            "release"
+        };
+        Origin {
+            commit,
+            machine,
+            profile: profile.to_string(),
+        }
+    }
+}
+
+/// One phase of one day, as timed by an earlier benchmark.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub origin: Origin,
+    /// Seconds since the unix epoch.
+    pub time: u64,
+    pub day: u8,
+    pub phase: Phase,
+    pub stats: Stats,
+}
+
+/// Earlier benchmarks, oldest first.
+/// Stored one phase per line as
+/// `<commit> <machine> <profile> <time> <day> <phase> <runs> <min> <median> <max> <mean> <std-dev>`,
+/// with durations in nanoseconds and `#` comments.
+/// Lines from before profiles were recorded have an `unknown` profile.
+pub struct BenchHistory {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+impl BenchHistory {
+    /// Reads the history file at `path`. A missing file has no history yet.
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        let mut entries = Vec::new();
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let entry = parse_entry(line).map_err(|reason| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}:{}: {}", path.display(), i + 1, reason),
+                )
+            })?;
+            entries.push(entry);
+        }
+        Ok(BenchHistory { path, entries })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// The latest earlier timing of `phase` of `day` on the machine and with
+    /// the profile of `origin`.
+    pub fn baseline(&self, origin: &Origin, day: u8, phase: Phase) -> Option<&Entry> {
+        self.entries.iter().rev().find(|entry| {
+            entry.origin.machine == origin.machine
+                && entry.origin.profile == origin.profile
+                && entry.day == day
+                && entry.phase == phase
+        })
+    }
+
+    /// Compares every phase of `report` against its baseline on the same
+    /// machine and profile.
+    pub fn compare(&self, origin: &Origin, report: &BenchReport) -> Vec<Comparison> {
+        report
+            .0
+            .iter()
+            .flat_map(|bench| {
+                bench
+                    .phases()
+                    .map(move |(phase, stats)| (bench.day, phase, stats))
+            })
+            .filter_map(|(day, phase, stats)| {
+                let baseline = self.baseline(origin, day, phase)?;
+                Some(Comparison {
+                    day,
+                    phase,
+                    baseline: baseline.origin.commit.clone(),
+                    before: baseline.stats,
+                    after: *stats,
+                })
+            })
+            .collect()
+    }
+
+    /// Appends every phase of `report` to the history, and to its file.
+    pub fn append(&mut self, origin: &Origin, report: &BenchReport) -> io::Result<()> {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs());
+        let new = report.0.iter().flat_map(|bench| {
+            bench.phases().map(|(phase, stats)| Entry {
+                origin: origin.clone(),
+                time,
+                day: bench.day,
+                phase,
+                stats: *stats,
+            })
+        });
+        let start = self.entries.len();
+        self.entries.extend(new);
+        let mut contents = String::new();
+        if !self.path.exists() {
+            contents.push_str(
+                "# <commit> <machine> <profile> <time> <day> <phase> \
+                 <runs> <min> <median> <max> <mean> <std-dev>\n",
+            );
+        }
+        for entry in &self.entries[start..] {
+            let nanos = |duration: Duration| duration.as_nanos();
+            contents.push_str(&format!(
+                "{} {} {} {} {} {} {} {} {} {} {} {}\n",
+                entry.origin.commit,
+                entry.origin.machine,
+                entry.origin.profile,
+                entry.time,
+                entry.day,
+                entry.phase,
+                entry.stats.runs,
+                nanos(entry.stats.min),
+                nanos(entry.stats.median),
+                nanos(entry.stats.max),
+                nanos(entry.stats.mean),
+                nanos(entry.stats.std_dev)
+            ));
+        }
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?
+            .write_all(contents.as_bytes())
+    }
+}
+
+fn parse_entry(line: &str) -> Result<Entry, String> {
+    let mut fields = line.split_whitespace().collect::<Vec<_>>();
+    if fields.len() == 11 {
+        fields.insert(2, "unknown");
+    }
+    let [commit, machine, profile, time, day, phase, runs, min, median, max, mean, std_dev] =
+        fields[..]
+    else {
+        return Err(format!("expected 12 fields, found {}", fields.len()));
+    };
+    let number = |name: &str, field: &str| {
+        field
+            .parse::<u64>()
+            .map_err(|e| format!("{} '{}' is not a number: {}", name, field, e))
+    };
+    let duration = |name: &str, field: &str| number(name, field).map(Duration::from_nanos);
+    Ok(Entry {
+        origin: Origin {
+            commit: commit.to_string(),
+            machine: machine.to_string(),
+            profile: profile.to_string(),
+        },
+        time: number("time", time)?,
+        day: number("day", day)?
+            .try_into()
+            .map_err(|_| format!("day '{}' is out of range", day))?,
+        phase: phase.parse()?,
+        stats: Stats {
+            runs: number("runs", runs)? as usize,
+            min: duration("min", min)?,
+            median: duration("median", median)?,
+            max: duration("max", max)?,
+            mean: duration("mean", mean)?,
+            std_dev: duration("std-dev", std_dev)?,
+        },
+    })
+}
+
+/// A phase timed now, next to its timing in the baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub phase: Phase,
+    /// Commit of the baseline.
+    pub baseline: String,
+    pub before: Stats,
+    pub after: Stats,
+}
+impl Comparison {
+    /// Relative change of the mean, positive when slower.
+    pub fn change(&self) -> f64 {
+        let before = self.before.mean.as_secs_f64();
+        if before == 0.0 {
+            return 0.0;
+        }
+        self.after.mean.as_secs_f64() / before - 1.0
+    }
+
+    /// Welch's t-statistic of the means, positive when slower.
+    /// Infinite for a change between runs that had no spread at all.
+    pub fn t(&self) -> f64 {
+        let variance = |stats: &Stats| stats.std_dev.as_secs_f64().powi(2) / stats.runs as f64;
+        let error = (variance(&self.before) + variance(&self.after)).sqrt();
+        let difference = self.after.mean.as_secs_f64() - self.before.mean.as_secs_f64();
+        match difference / error {
+            t if t.is_nan() => 0.0,
+            t => t,
+        }
+    }
+
+    /// Slower by more than noise and more than a few percent.
+    /// Needs several runs on both sides to say anything.
+    pub fn is_slowdown(&self) -> bool {
+        self.before.runs > 1
+            && self.after.runs > 1
+            && self.change() > MIN_SLOWDOWN
+            && self.t() > MIN_T
+    }
+}
+impl Display for Comparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:>3} {:>5}  {:>12} -> {:<12} {:>+7.1}% (t = {:.1}, against {})",
+            self.day,
+            self.phase,
+            format!("{:.2?}", self.before.mean),
+            format!("{:.2?}", self.after.mean),
+            self.change() * 100.0,
+            self.t(),
+            self.baseline
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn stats(millis: [u64; 5]) -> Stats {
+        Stats::from_samples(millis.map(Duration::from_millis).to_vec())
+    }
+
+    #[test]
+    fn only_consistent_slowdowns_are_flagged() {
+        let comparison = |before, after| Comparison {
+            day: 1,
+            phase: Phase::Part(1),
+            baseline: "abc1234".to_string(),
+            before: stats(before),
+            after: stats(after),
+        };
+        assert!(comparison([10, 11, 10, 9, 10], [20, 21, 19, 20, 20]).is_slowdown());
+        assert!(!comparison([20, 21, 19, 20, 20], [10, 11, 10, 9, 10]).is_slowdown());
+        assert!(!comparison([10, 30, 5, 20, 10], [20, 10, 35, 5, 15]).is_slowdown());
+        assert!(!comparison([100, 101, 100, 99, 100], [102, 103, 102, 101, 102]).is_slowdown());
+    }
+
+    #[test]
+    fn entries_parse() {
+        let line = "abc1234-dirty box release 1734000000 7 p2 10 1000 1500 3000 1700 400";
+        let entry = parse_entry(line).unwrap();
+        assert_eq!(entry.origin.profile, "release");
+        assert_eq!(entry.day, 7);
+        assert_eq!(entry.phase, Phase::Part(2));
+        assert_eq!(entry.stats.median, Duration::from_nanos(1500));
+        let line = "abc1234-dirty box 1734000000 7 p2 10 1000 1500 3000 1700 400";
+        assert_eq!(parse_entry(line).unwrap().origin.profile, "unknown");
+        assert!(parse_entry("abc1234 box 1734000000 7 p2").is_err());
+    }
+
+    #[test]
+    fn baselines_are_of_the_same_profile() {
+        let history = BenchHistory {
+            path: PathBuf::new(),
+            entries: [
+                "abc1234 box release 1734000000 7 p1 10 1000 1500 3000 1700 400",
+                "def5678 box debug 1734000100 7 p1 10 9000 9500 9900 9600 400",
+            ]
+            .map(|line| parse_entry(line).unwrap())
+            .to_vec(),
+        };
+        let origin = |profile: &str| Origin {
+            commit: "0123abc".to_string(),
+            machine: "box".to_string(),
+            profile: profile.to_string(),
+        };
+        let baseline = |profile| history.baseline(&origin(profile), 7, Phase::Part(1));
+        assert_eq!(baseline("release").unwrap().origin.commit, "abc1234");
+        assert_eq!(baseline("debug").unwrap().origin.commit, "def5678");
+        assert!(baseline("unknown").is_none());
+    }
+}
@@ -1,31 +1,61 @@
 #![allow(unused)]
 
+mod answers;
+mod bench;
+mod fetch;
+pub mod fixture;
+pub mod grid;
+mod history;
+mod outcome;
+pub mod puzzle;
+mod registry;
+pub mod scaffold;
+pub mod search;
+pub mod svg;
+mod solution;
+pub mod trace;
+pub mod viz;
+
+pub use answers::{Answers, Hint, Objection, Verdict};
+pub use bench::{bench_day, BenchReport, DayBench, Phase, Stats};
+pub use fetch::{FetchError, Fetcher, DEFAULT_BASE_URL};
+pub use grid::{Coord, Grid};
+pub use history::{BenchHistory, Comparison, Entry, Origin};
+pub use outcome::{isolate, Outcome, Progress};
+pub use registry::{Day, ParseFn, Parsed, PartFn, Registry};
+pub use solution::{ParseError, ParseReport, Solution};
+
 /// Input that a solver can take.
 pub struct DayInput<S: AsRef<str>>(S);
 impl DayInput<String> {
-    /// Tries finding the relevant input, either by accepting a string or
-    /// falling back to some maybe-existing file.
-    /// The `inputs` folder may be a private submodule.
-    pub fn find<const DAY: u8>() -> Self {
+    /// Tries finding the relevant input, either by an explicit path or
+    /// falling back to the day's file in the `inputs` folder.
+    /// The `inputs` folder may be a private submodule, see [`Fetcher`] for filling it.
+    pub fn find(inputs: impl AsRef<Path>, day: u8, path: Option<&Path>) -> std::io::Result<Self> {
         let absolute = {
-            let relative_path = std::env::args().nth(1).unwrap_or_else(|| {
-                let default = format!("inputs/{}", DAY);
-                eprintln!("No path specified, assuming you want '{}'.", default);
-                default
-            });
-            std::path::absolute(&relative_path).expect("absolute path creation")
+            let relative_path =
+                path.map_or_else(|| inputs.as_ref().join(day.to_string()), Path::to_path_buf);
+            std::path::absolute(&relative_path)?
         };
-        eprint!("Looking for {:?}... ", absolute);
-        let x = std::fs::read_to_string(absolute).expect("file needs to exist");
-        eprintln!("Found file!\n");
-        DayInput(x)
+        log::debug!("Reading {:?}", absolute);
+        let x = std::fs::read_to_string(absolute)?;
+        Ok(DayInput(x))
     }
 
-    // eliding lifetimes makes function "not general enough"
-    #[allow(clippy::needless_lifetimes)]
-    pub fn solve_with<'s, O: Display>(&'s self, solver: impl FnOnce(&'s Self) -> O) {
-        let o = solver(self);
-        println!("Result: {}", o);
+    /// Downloads the input of `day` into `inputs`, unless it is already there.
+    pub fn fetch(inputs: impl AsRef<Path>, day: u8, fetcher: &Fetcher) -> Result<Self, FetchError> {
+        fetcher.cached(inputs, day).map(DayInput)
+    }
+
+    /// Applies the cleanups a solver asked for, see [`Normalize`].
+    pub fn normalize(self, normalize: Normalize) -> Self {
+        DayInput(normalize.apply(&self.0))
+    }
+
+    /// Keeps the input around for the rest of the run,
+    /// such that parsed models can borrow from it.
+    pub fn leak(self) -> &'static str {
+        self.0.leak()
     }
 }
 impl AsRef<str> for DayInput<String> {
@@ -34,18 +64,81 @@ impl AsRef<str> for DayInput<String> {
     }
 }
 
-// dev-only prints
-#[cfg(debug_assertions)]
-macro_rules! dprintln {
-    ($x:expr) => {
-        eprintln!($x);
-    };
+/// Cleanups a solver declares it needs, such that the same input works
+/// whether it was saved on Windows, pasted from a browser or fetched.
+/// Inputs are used as they are unless a solver opts in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct Normalize {
+    /// Turns `\r\n` line endings into `\n`.
+    pub crlf: bool,
+    /// Drops a byte order mark at the start.
+    pub bom: bool,
+    /// Drops spaces and tabs at the end of every line.
+    pub trailing_whitespace: bool,
+    /// Drops every line ending at the end, leaving no empty last line.
+    pub trailing_newlines: bool,
 }
-#[cfg(not(debug_assertions))]
-macro_rules! dprintln {
-    ($x:expr) => {}; // no-op
+impl Normalize {
+    pub const NONE: Normalize = Normalize {
+        crlf: false,
+        bom: false,
+        trailing_whitespace: false,
+        trailing_newlines: false,
+    };
+    pub const ALL: Normalize = Normalize {
+        crlf: true,
+        bom: true,
+        trailing_whitespace: true,
+        trailing_newlines: true,
+    };
+
+    pub fn apply(&self, input: &str) -> String {
+        let mut input = input;
+        if self.bom {
+            input = input.strip_prefix('\u{feff}').unwrap_or(input);
+        }
+        let mut normalized = if self.crlf {
+            input.replace("\r\n", "\n")
+        } else {
+            input.to_string()
+        };
+        if self.trailing_whitespace {
+            normalized = normalized
+                .split('\n')
+                .map(|line| match line.strip_suffix('\r') {
+                    Some(line) => format!("{}\r", line.trim_end_matches([' ', '\t'])),
+                    None => line.trim_end_matches([' ', '\t']).to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+        }
+        if self.trailing_newlines {
+            normalized.truncate(normalized.trim_end_matches(['\r', '\n']).len());
+        }
+        normalized
+    }
 }
 
-use std::fmt::Display;
+use std::path::Path;
+
+#[cfg(test)]
+mod tests {
+    use super::Normalize;
 
-pub(crate) use dprintln;
+    #[test]
+    fn normalization_is_opt_in() {
+        let saved = "\u{feff}1 2 \r\n3 4\t\r\n\r\n";
+        assert_eq!(Normalize::NONE.apply(saved), saved);
+        assert_eq!(Normalize::ALL.apply(saved), "1 2\n3 4");
+        let crlf = Normalize {
+            crlf: true,
+            ..Normalize::NONE
+        };
+        assert_eq!(crlf.apply(saved), "\u{feff}1 2 \n3 4\t\n\n");
+        let whitespace = Normalize {
+            trailing_whitespace: true,
+            ..Normalize::NONE
+        };
+        assert_eq!(whitespace.apply(saved), "\u{feff}1 2\r\n3 4\r\n\r\n");
+    }
+}
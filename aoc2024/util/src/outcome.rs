@@ -0,0 +1,189 @@
+//! Solving parts without a panic in one of them ending the whole run.
+//! Parts that are still `todo!()` or `unimplemented!()` count as unsolved,
+//! other panics as failures with their message. Runners that solve parts in
+//! a process of their own also report parts that ran out of time or memory.
+
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    fmt::{Display, Formatter},
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+    time::Duration,
+};
+
+thread_local! {
+    static ISOLATING: Cell<bool> = const { Cell::new(false) };
+    /// Where the last panic while isolating happened, as the hook saw it.
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+static QUIET_HOOK: Once = Once::new();
+
+/// How a part went.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Outcome {
+    Solved(String),
+    Unsolved,
+    Failed(String),
+    /// Still running when its time was up.
+    TimedOut(Duration),
+    /// Went over its memory limit.
+    OutOfMemory,
+}
+impl Outcome {
+    /// Runs `solve`, catching a panic in it.
+    pub fn of(solve: impl FnOnce() -> String) -> Self {
+        match isolate(solve) {
+            Ok(answer) => Outcome::Solved(answer),
+            Err(outcome) => outcome,
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        matches!(self, Outcome::Solved(_))
+    }
+}
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Solved(answer) => write!(f, "{}", answer),
+            Outcome::Unsolved => write!(f, "unsolved"),
+            Outcome::Failed(reason) => write!(f, "failed: {}", reason),
+            Outcome::TimedOut(limit) => write!(f, "timeout after {:?}", limit),
+            Outcome::OutOfMemory => write!(f, "OOM"),
+        }
+    }
+}
+
+/// Runs `run`, giving back [`Outcome::Unsolved`] or [`Outcome::Failed`] if it panics.
+/// The panic is not printed, as the outcome says what it was.
+pub fn isolate<T>(run: impl FnOnce() -> T) -> Result<T, Outcome> {
+    QUIET_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if ISOLATING.get() {
+                let location = info.location().map(ToString::to_string);
+                LOCATION.set(location);
+            } else {
+                previous(info);
+            }
+        }));
+    });
+    let was_isolating = ISOLATING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(run));
+    ISOLATING.set(was_isolating);
+    result.map_err(|payload| classify(payload.as_ref(), LOCATION.take()))
+}
+
+fn classify(payload: &(dyn Any + Send), location: Option<String>) -> Outcome {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("panicked");
+    // the messages of `todo!()` and `unimplemented!()`, with or without a reason
+    if ["not yet implemented", "not implemented"]
+        .iter()
+        .any(|todo| message == *todo || message.starts_with(&format!("{}: ", todo)))
+    {
+        return Outcome::Unsolved;
+    }
+    match location {
+        Some(location) => Outcome::Failed(format!("{} (at {})", message, location)),
+        None => Outcome::Failed(message.to_string()),
+    }
+}
+
+/// How many parts of a run went which way.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct Progress {
+    pub solved: usize,
+    pub unsolved: usize,
+    pub failed: usize,
+    pub timed_out: usize,
+    pub out_of_memory: usize,
+}
+impl Progress {
+    pub fn add(&mut self, outcome: &Outcome) {
+        match outcome {
+            Outcome::Solved(_) => self.solved += 1,
+            Outcome::Unsolved => self.unsolved += 1,
+            Outcome::Failed(_) => self.failed += 1,
+            Outcome::TimedOut(_) => self.timed_out += 1,
+            Outcome::OutOfMemory => self.out_of_memory += 1,
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.solved + self.unsolved + self.failed + self.timed_out + self.out_of_memory
+    }
+}
+impl std::ops::AddAssign for Progress {
+    fn add_assign(&mut self, other: Self) {
+        self.solved += other.solved;
+        self.unsolved += other.unsolved;
+        self.failed += other.failed;
+        self.timed_out += other.timed_out;
+        self.out_of_memory += other.out_of_memory;
+    }
+}
+impl Display for Progress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let percent = (100 * self.solved).checked_div(self.total()).unwrap_or(0);
+        write!(
+            f,
+            "{}/{} solved ({}%), {} unsolved, {} failed",
+            self.solved,
+            self.total(),
+            percent,
+            self.unsolved,
+            self.failed
+        )?;
+        // only runs with limits have these
+        if self.timed_out > 0 {
+            write!(f, ", {} timed out", self.timed_out)?;
+        }
+        if self.out_of_memory > 0 {
+            write!(f, ", {} out of memory", self.out_of_memory)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panics_become_outcomes() {
+        let outcomes = [
+            Outcome::of(|| "42".to_string()),
+            Outcome::of(|| todo!()),
+            Outcome::of(|| unimplemented!("needs a cycle finder")),
+            Outcome::of(|| panic!("no path from {} to {}", 1, 2)),
+        ];
+        assert_eq!(outcomes[0], Outcome::Solved("42".to_string()));
+        assert_eq!(outcomes[1], Outcome::Unsolved);
+        assert_eq!(outcomes[2], Outcome::Unsolved);
+        let Outcome::Failed(reason) = &outcomes[3] else {
+            panic!("expected a failure, got {:?}", outcomes[3]);
+        };
+        assert!(reason.starts_with("no path from 1 to 2 (at "), "{}", reason);
+        assert!(reason.contains("outcome.rs"), "{}", reason);
+        assert_eq!(isolate(|| 7), Ok(7));
+
+        let mut progress = Progress::default();
+        for outcome in &outcomes {
+            progress.add(outcome);
+        }
+        assert_eq!(
+            progress.to_string(),
+            "1/4 solved (25%), 2 unsolved, 1 failed"
+        );
+        progress.add(&Outcome::TimedOut(Duration::from_secs(10)));
+        assert_eq!(
+            progress.to_string(),
+            "1/5 solved (20%), 2 unsolved, 1 failed, 1 timed out"
+        );
+    }
+}
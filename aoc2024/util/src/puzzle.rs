@@ -0,0 +1,496 @@
+//! Examples pulled out of the puzzle descriptions (`p1.md`, `p2.md`),
+//! such that they need not be copied into fixtures and tests by hand.
+
+use std::{io, path::Path};
+
+use crate::{isolate, Outcome, Registry};
+
+/// What a puzzle description shows: its code blocks, and the answers
+/// it emphasizes (like `` _`3749`_ ``), both in order of appearance.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Description {
+    pub blocks: Vec<Block>,
+    pub answers: Vec<Answer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    pub code: String,
+    /// Whether the text leading into it announces an example, like "For example:".
+    /// Other blocks tend to show steps of solving one.
+    pub introduced: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub value: String,
+    /// How many code blocks come before it.
+    pub blocks_before: usize,
+}
+impl Description {
+    /// Reads indented and fenced code blocks, and emphasized code spans.
+    pub fn parse(markdown: &str) -> Self {
+        let mut description = Description::default();
+        let mut block: Option<Vec<&str>> = None;
+        let mut fenced = false;
+        let mut lead_in = "";
+        for line in markdown.lines() {
+            if line.trim_start().starts_with("```") {
+                match block.take() {
+                    Some(lines) if fenced => description.push_block(lead_in, lines, true),
+                    _ => block = Some(Vec::new()),
+                }
+                fenced = block.is_some();
+                continue;
+            }
+            if fenced {
+                block.get_or_insert_with(Vec::new).push(line);
+                continue;
+            }
+            match (line.strip_prefix("    "), &mut block) {
+                (Some(code), Some(lines)) => lines.push(code),
+                (Some(code), None) => block = Some(vec![code]),
+                // blank lines within a block, trimmed again at its end
+                (None, Some(lines)) if line.trim().is_empty() => lines.push(""),
+                (None, _) if line.trim().is_empty() => {}
+                (None, _) => {
+                    if let Some(lines) = block.take() {
+                        description.push_block(lead_in, lines, true);
+                    }
+                    lead_in = line;
+                    let blocks_before = description.blocks.len();
+                    description
+                        .answers
+                        .extend(emphasized_code(line).into_iter().map(|value| Answer {
+                            value,
+                            blocks_before,
+                        }));
+                }
+            }
+        }
+        if let Some(lines) = block {
+            // unless the last line of the block is the last of a file without a newline
+            let newline = markdown.ends_with('\n') || lines.last().is_some_and(|l| l.is_empty());
+            description.push_block(lead_in, lines, newline);
+        }
+        description
+    }
+
+    /// Adds a block of `lines`, ending in a newline if the last of them did.
+    fn push_block(&mut self, lead_in: &str, mut lines: Vec<&str>, newline: bool) {
+        while lines.last().is_some_and(|line| line.trim().is_empty()) {
+            lines.pop();
+        }
+        if lines.is_empty() {
+            return;
+        }
+        let lead_in = lead_in.to_lowercase();
+        let words = lead_in.split_whitespace().collect::<Vec<_>>();
+        let introduced = lead_in.contains("for example")
+            || words[words.len().saturating_sub(4)..]
+                .iter()
+                .any(|word| word.starts_with("example"));
+        let mut code = lines.join("\n");
+        if newline {
+            code.push('\n');
+        }
+        self.blocks.push(Block { code, introduced });
+    }
+
+    /// The last emphasized answer, which the puzzles give for the whole example
+    /// right before asking for the answer to the real input.
+    pub fn answer(&self) -> Option<&Answer> {
+        self.answers.last()
+    }
+
+    /// The example that [`Description::answer`] is about:
+    /// the last block before it that was introduced as one.
+    pub fn example(&self) -> Option<&str> {
+        let before = self.answer()?.blocks_before;
+        self.blocks[..before]
+            .iter()
+            .rfind(|block| block.introduced)
+            .map(|block| block.code.as_str())
+    }
+}
+
+/// Code spans wrapped in `_` or `*`, like `` _`11`_ `` or `` **`48`** ``.
+fn emphasized_code(line: &str) -> Vec<String> {
+    let mut spans = Vec::new();
+    let mut rest = line;
+    while let Some(open) = rest.find('`') {
+        let Some(length) = rest[open + 1..].find('`') else {
+            break;
+        };
+        let close = open + 1 + length;
+        let before = rest[..open].chars().next_back();
+        let after = rest[close + 1..].chars().next();
+        if matches!(before, Some('_' | '*')) && before == after {
+            spans.push(rest[open + 1..close].to_string());
+        }
+        rest = &rest[close + 1..];
+    }
+    spans
+}
+
+/// The descriptions of the parts on a puzzle page, one per `<article>`,
+/// in the markdown that `p1.md` and `p2.md` are kept in.
+/// Title texts (`<span title>`) are kept as they are.
+pub fn markdown_from_html(html: &str, year: u16, day: u8) -> Vec<String> {
+    let url = format!("https://adventofcode.com/{}/day/{}", year, day);
+    let mut title = String::new();
+    let mut parts = Vec::new();
+    for article in html.split("<article").skip(1) {
+        let Some((_, body)) = article.split_once('>') else {
+            continue;
+        };
+        let body = body.split("</article>").next().unwrap_or(body);
+        let (heading, text) = html_to_markdown(body);
+        // "--- Day 7: Bridge Repair ---", then "--- Part Two ---"
+        let heading = heading.trim_matches(['-', ' ']);
+        let part = if parts.is_empty() {
+            title = heading.to_string();
+            "Part One"
+        } else {
+            "Part Two"
+        };
+        parts.push(format!(
+            "# {}\n\n[{}]({})\n\n## Description\n\n### {}\n\n{}\n",
+            title,
+            url,
+            url,
+            part,
+            text.trim_end()
+        ));
+    }
+    parts
+}
+
+/// The `<h2>` text, and everything else as markdown.
+fn html_to_markdown(html: &str) -> (String, String) {
+    let mut heading = String::new();
+    let mut text = String::new();
+    let mut in_heading = false;
+    let mut pre_start = None;
+    let mut href = None;
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(after) = rest.strip_prefix('<') else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let decoded = decode_entities(&rest[..end]);
+            rest = &rest[end..];
+            if in_heading {
+                heading.push_str(&decoded);
+            } else if pre_start.is_some()
+                || !(decoded.trim().is_empty() && (text.is_empty() || text.ends_with('\n')))
+            {
+                text.push_str(&decoded);
+            }
+            continue;
+        };
+        if after.starts_with("span") {
+            let end = after
+                .find("</span>")
+                .map_or(after.len(), |end| end + "</span>".len());
+            text.push('<');
+            text.push_str(&after[..end]);
+            rest = &after[end..];
+            continue;
+        }
+        let Some((tag, after)) = after.split_once('>') else {
+            break;
+        };
+        rest = after;
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split_whitespace()
+            .next()
+            .unwrap_or("");
+        match (name, closing) {
+            ("h2", _) => in_heading = !closing,
+            ("p", true) => text.push_str("\n\n"),
+            ("pre", false) => pre_start = Some(text.len()),
+            ("pre", true) => {
+                let code = text.split_off(pre_start.take().unwrap_or(text.len()));
+                for line in code.trim_end_matches('\n').split('\n') {
+                    text.push_str("    ");
+                    text.push_str(line);
+                    text.push('\n');
+                }
+                text.push_str("    \n\n");
+            }
+            // tags within code blocks only highlight
+            _ if pre_start.is_some() => {}
+            ("em", _) => text.push('_'),
+            ("code", _) => text.push('`'),
+            ("li", false) => text.push_str("*   "),
+            ("li", true) => text.push('\n'),
+            ("ul", true) => text.push('\n'),
+            ("a", false) => {
+                href = attribute(tag, "href").map(|link| {
+                    if link.starts_with('/') {
+                        format!("https://adventofcode.com{}", link)
+                    } else {
+                        link
+                    }
+                });
+                text.push('[');
+            }
+            ("a", true) => {
+                if let Some(href) = href.take() {
+                    text.push_str(&format!("]({})", href));
+                }
+            }
+            _ => {}
+        }
+    }
+    (heading, text)
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let (_, value) = tag.split_once(&format!("{}=\"", name))?;
+    let (value, _) = value.split_once('"')?;
+    Some(decode_entities(value))
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// What [`ingest`] found for one part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub part: u8,
+    /// Name of the fixture next to the day's sources.
+    pub fixture: String,
+    pub answer: String,
+    /// Why its test is ignored, if the day does not give the answer for it yet.
+    pub ignored: Option<String>,
+}
+
+/// Why the registered solver of `part` of `day` does not give `answer` for `example`, if it does not.
+fn mismatch(registry: &Registry, day: u8, part: u8, example: &str, answer: &str) -> Option<String> {
+    match isolate(|| registry.answer(day, part, example)) {
+        Ok(Some(Ok(given))) if given == answer => None,
+        Ok(Some(Ok(given))) => Some(format!("gives {}", given)),
+        Ok(Some(Err(_))) => Some("does not parse".to_string()),
+        Ok(None) => Some("not registered".to_string()),
+        Err(Outcome::Unsolved) => Some("unsolved".to_string()),
+        Err(_) => Some("panics".to_string()),
+    }
+}
+
+/// Writes the example that the answer of each part of the day crate at
+/// `day_dir` is about as a `P{part}_EXAMPLE` fixture, with a generated
+/// `examples.rs` test module that solves them through the day's registration
+/// and compares the answers. A part without its own code block before the
+/// answer that is introduced as an example reuses the example of part one.
+/// Parts without a description or answer are left out. The tests of parts
+/// whose solver in `registry` does not give the answer yet are ignored,
+/// such that the tests still pass until it does.
+pub fn ingest(day_dir: impl AsRef<Path>, day: u8, registry: &Registry) -> io::Result<Vec<Example>> {
+    let day_dir = day_dir.as_ref();
+    let src = day_dir.join("src");
+    let mut examples = Vec::new();
+    let mut previous_fixture: Option<(String, String)> = None;
+    for part in 1..=2 {
+        let markdown = match std::fs::read_to_string(day_dir.join(format!("p{}.md", part))) {
+            Ok(markdown) => markdown,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        };
+        let description = Description::parse(&markdown);
+        let (fixture, example) = match description.example() {
+            Some(example) => {
+                let fixture = format!("P{}_EXAMPLE", part);
+                std::fs::write(src.join(&fixture), example)?;
+                (fixture, example.to_string())
+            }
+            None => match &previous_fixture {
+                Some(previous) => previous.clone(),
+                None => continue,
+            },
+        };
+        previous_fixture = Some((fixture.clone(), example.clone()));
+        if let Some(answer) = description.answer() {
+            examples.push(Example {
+                part,
+                fixture,
+                answer: answer.value.clone(),
+                ignored: mismatch(registry, day, part, &example, &answer.value),
+            });
+        }
+    }
+    if examples.is_empty() {
+        return Ok(examples);
+    }
+    std::fs::write(src.join("examples.rs"), test_module(day, &examples))?;
+    let lib = src.join("lib.rs");
+    let contents = std::fs::read_to_string(&lib)?;
+    if !contents.contains("mod examples;") {
+        std::fs::write(&lib, declare_examples(&contents))?;
+    }
+    Ok(examples)
+}
+
+/// Declares the `examples` test module after the crate's other modules,
+/// or else after its inner attributes, which have to come first.
+fn declare_examples(lib: &str) -> String {
+    let lines = lib.lines().collect::<Vec<_>>();
+    let is_mod = |line: &&str| line.starts_with("mod ") || line.starts_with("pub mod ");
+    let at = match lines.iter().rposition(is_mod) {
+        Some(last_mod) => last_mod + 1,
+        None => lines
+            .iter()
+            .position(|line| !(line.starts_with("#![") || line.starts_with("//!")))
+            .unwrap_or(lines.len()),
+    };
+    let mut declared = lines[..at].join("\n");
+    if at > 0 {
+        declared.push('\n');
+    }
+    declared.push_str("#[cfg(test)]\nmod examples;\n");
+    for line in &lines[at..] {
+        declared.push_str(line);
+        declared.push('\n');
+    }
+    declared
+}
+
+fn test_module(day: u8, examples: &[Example]) -> String {
+    let mut module = String::from(
+        "//! Generated by `aoc ingest` from the puzzle descriptions.\n\
+         //! Ingesting again overwrites this file.\n",
+    );
+    for example in examples {
+        let ignore = match &example.ignored {
+            Some(reason) => format!("#[ignore = {:?}]\n", reason),
+            None => String::new(),
+        };
+        module.push_str(&format!(
+            r#"
+#[test]
+{ignore}fn p{part}_example_solvable() {{
+    let mut registry = util::Registry::new();
+    crate::register(&mut registry);
+    let answer = registry.answer({day}, {part}, include_str!("{fixture}"));
+    assert_eq!(answer.expect("part is registered").unwrap(), {answer:?});
+}}
+"#,
+            ignore = ignore,
+            part = example.part,
+            day = day,
+            fixture = example.fixture,
+            answer = example.answer
+        ));
+    }
+    module
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART: &str = "\
+### Part One
+
+For example:
+
+    3   4
+    4   3
+
+The distance between them is _`1`_. Here's a larger example:
+
+    3   4
+    4   3
+
+    1   3
+
+Sorted, these are:
+
+    1   3
+    3   3
+
+In the example above, this is `2 + 1 + 2`, a total distance of _`5`_!
+
+```
+fenced
+```
+";
+
+    #[test]
+    fn examples_and_answers_are_found() {
+        let description = Description::parse(PART);
+        let larger = "3   4\n4   3\n\n1   3\n";
+        let blocks = description
+            .blocks
+            .iter()
+            .map(|b| (b.code.as_str(), b.introduced));
+        assert_eq!(
+            blocks.collect::<Vec<_>>(),
+            [
+                ("3   4\n4   3\n", true),
+                (larger, true),
+                ("1   3\n3   3\n", false),
+                ("fenced\n", false)
+            ]
+        );
+        let values = |d: &Description| {
+            d.answers
+                .iter()
+                .map(|a| a.value.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(values(&description), ["1", "5"]);
+        assert_eq!(description.answer().unwrap().value, "5");
+        assert_eq!(description.example(), Some(larger));
+        let starred = Description::parse("a total of **`48`** and *`7`*");
+        assert_eq!(values(&starred), ["48", "7"]);
+        assert_eq!(starred.example(), None);
+    }
+
+    #[test]
+    fn pages_convert_to_markdown() {
+        let html = "<main>\n<article class=\"day-desc\"><h2>--- Day 1: Historian Hysteria ---</h2>\
+            <p>The <em>Chief Historian</em> is <a href=\"/2024/events\">always</a> \
+            <span title=\"&quot;Hm&quot;\">there</span>.</p>\n\
+            <p>For example:</p>\n<pre><code>3   4\n<em>4</em>   3\n</code></pre>\n\
+            <ul>\n<li><code>1</code> &lt; <code>3</code></li>\n</ul>\n\
+            <p>A total of <em><code>11</code></em>!</p>\n</article>\n\
+            <article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Again.</p></article>";
+        let parts = markdown_from_html(html, 2024, 1);
+        assert_eq!(
+            parts[0],
+            "# Day 1: Historian Hysteria\n\n\
+             [https://adventofcode.com/2024/day/1](https://adventofcode.com/2024/day/1)\n\n\
+             ## Description\n\n### Part One\n\n\
+             The _Chief Historian_ is [always](https://adventofcode.com/2024/events) \
+             <span title=\"&quot;Hm&quot;\">there</span>.\n\n\
+             For example:\n\n    3   4\n    4   3\n    \n\n\
+             *   `1` < `3`\n\nA total of _`11`_!\n"
+        );
+        assert!(parts[1].ends_with("### Part Two\n\nAgain.\n"));
+        let description = Description::parse(&parts[0]);
+        assert_eq!(description.example(), Some("3   4\n4   3\n"));
+    }
+
+    #[test]
+    fn examples_are_declared_after_modules() {
+        let lib =
+            "#![feature(iter_map_windows)]\n\npub mod p1;\nmod parse;\n\npub fn register() {}\n";
+        assert_eq!(
+            declare_examples(lib),
+            "#![feature(iter_map_windows)]\n\npub mod p1;\nmod parse;\n\
+             #[cfg(test)]\nmod examples;\n\npub fn register() {}\n"
+        );
+        assert_eq!(
+            declare_examples("#![doc = \"\"]\nfn f() {}\n"),
+            "#![doc = \"\"]\n#[cfg(test)]\nmod examples;\nfn f() {}\n"
+        );
+    }
+}
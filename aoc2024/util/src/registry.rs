@@ -0,0 +1,148 @@
+use std::{any::Any, collections::BTreeMap, fmt::Display, ops::RangeInclusive};
+
+use crate::{Normalize, ParseError, Solution};
+
+/// A day's parsed model, with its type erased.
+pub type Parsed = Box<dyn Any>;
+/// Parses the input of a day. Inputs are kept for the whole run (see
+/// [`crate::DayInput::leak`]), so models may borrow from them.
+pub type ParseFn = Box<dyn Fn(&'static str) -> Result<Parsed, ParseError>>;
+/// A solver for a single part, with its output already rendered.
+pub type PartFn = Box<dyn Fn(&Parsed) -> String>;
+
+/// The parse step and part solvers of a single day.
+pub struct Day {
+    parse: ParseFn,
+    parts: BTreeMap<u8, PartFn>,
+    normalize: Normalize,
+}
+impl Day {
+    /// Parts that do their own parsing get the input as it is.
+    fn unparsed() -> Self {
+        Day {
+            parse: Box::new(|input| Ok(Box::new(input))),
+            parts: BTreeMap::new(),
+            normalize: Normalize::NONE,
+        }
+    }
+
+    /// Cleanups the input needs before [`Day::parse`].
+    pub fn normalize(&self) -> Normalize {
+        self.normalize
+    }
+
+    pub fn parse(&self, input: &'static str) -> Result<Parsed, ParseError> {
+        (self.parse)(input)
+    }
+
+    /// Solves `part` using a model from this day's [`Day::parse`].
+    pub fn solve(&self, part: u8, parsed: &Parsed) -> Option<String> {
+        self.parts.get(&part).map(|solver| solver(parsed))
+    }
+
+    pub fn parts(&self) -> impl Iterator<Item = u8> + '_ {
+        self.parts.keys().copied()
+    }
+}
+
+/// Every day that day crates know about, ordered by day.
+#[derive(Default)]
+pub struct Registry {
+    days: BTreeMap<u8, Day>,
+}
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a solver for `part` of `day` that does its own parsing.
+    /// Registering the same part twice is a mistake in the day crate.
+    pub fn register<O: Display>(
+        &mut self,
+        day: u8,
+        part: u8,
+        solver: impl Fn(&str) -> O + 'static,
+    ) {
+        let entry = self.days.entry(day).or_insert_with(Day::unparsed);
+        let previous = entry.parts.insert(
+            part,
+            Box::new(move |parsed| {
+                let input = parsed
+                    .downcast_ref::<&str>()
+                    .expect("day was registered both as parts and as a solution");
+                solver(input).to_string()
+            }),
+        );
+        assert!(
+            previous.is_none(),
+            "day {} part {} was registered twice",
+            day,
+            part
+        );
+    }
+
+    /// Adds both parts of `day`, sharing a single parse.
+    pub fn register_solution<S: Solution + 'static>(&mut self, day: u8)
+    where
+        S::Parsed<'static>: 'static,
+    {
+        fn model<S: Solution>(parsed: &Parsed) -> &S::Parsed<'static>
+        where
+            S::Parsed<'static>: 'static,
+        {
+            parsed
+                .downcast_ref()
+                .expect("model comes from the same solution")
+        }
+        let parts: [(u8, PartFn); 2] = [
+            (
+                1,
+                Box::new(|parsed| S::part1(model::<S>(parsed)).to_string()),
+            ),
+            (
+                2,
+                Box::new(|parsed| S::part2(model::<S>(parsed)).to_string()),
+            ),
+        ];
+        let previous = self.days.insert(
+            day,
+            Day {
+                parse: Box::new(|input| {
+                    let parsed: S::Parsed<'static> = S::parse(input)?;
+                    Ok(Box::new(parsed))
+                }),
+                parts: BTreeMap::from(parts),
+                normalize: S::NORMALIZE,
+            },
+        );
+        assert!(previous.is_none(), "day {} was registered twice", day);
+    }
+
+    /// Declares the cleanups the parts of `day` need their input to have.
+    /// Solutions declare these as [`Solution::NORMALIZE`] instead.
+    pub fn normalize(&mut self, day: u8, normalize: Normalize) {
+        self.days.entry(day).or_insert_with(Day::unparsed).normalize = normalize;
+    }
+
+    pub fn get(&self, day: u8) -> Option<&Day> {
+        self.days.get(&day)
+    }
+
+    /// Solves `part` of `day` for a single input, like an example,
+    /// with the cleanups the day declares. `None` if the part is not registered.
+    pub fn answer(&self, day: u8, part: u8, input: &str) -> Option<Result<String, ParseError>> {
+        let solvers = self.get(day)?;
+        solvers.parts.get(&part)?;
+        let input = solvers.normalize.apply(input).leak();
+        Some(
+            solvers
+                .parse(input)
+                .map(|parsed| solvers.solve(part, &parsed).expect("part is registered")),
+        )
+    }
+
+    /// Every registered day within `days`.
+    pub fn select(&self, days: RangeInclusive<u8>) -> impl Iterator<Item = (u8, &Day)> {
+        self.days.range(days).map(|(day, solvers)| (*day, solvers))
+    }
+}
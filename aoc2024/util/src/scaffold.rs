@@ -0,0 +1,275 @@
+//! New day crates, made from `day_template` in the workspace.
+//! The template marks the day number with `_`: in its package name (`day_`)
+//! and its registration (`register(_, ...)`).
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+/// Creates the crate of `day` from the `day_template` of `workspace` with the
+/// day number filled in, and registers it as a member and dependency in the
+/// workspace `Cargo.toml` and with the runners in `src/lib.rs`.
+/// Gives back the directory of the new crate.
+pub fn new_day(workspace: impl AsRef<Path>, day: u8) -> io::Result<PathBuf> {
+    let workspace = workspace.as_ref();
+    if !(1..=25).contains(&day) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("there is no day {}", day),
+        ));
+    }
+    let dir = workspace.join(format!("day{}", day));
+    if dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", dir.display()),
+        ));
+    }
+    let manifest = workspace.join("Cargo.toml");
+    let lib = workspace.join("src").join("lib.rs");
+    // edit in memory first, such that nothing is written if either cannot be edited
+    let registered_manifest = edit(&manifest, |contents| register_manifest(contents, day))?;
+    let registered_lib = edit(&lib, |contents| {
+        insert_day_line(
+            contents,
+            day,
+            |line| day_of(line, "::register(&mut registry);"),
+            format!("    day{}::register(&mut registry);", day),
+        )
+    })?;
+    copy_template(&workspace.join("day_template"), &dir, day)?;
+    std::fs::write(&manifest, registered_manifest)?;
+    std::fs::write(&lib, registered_lib)?;
+    Ok(dir)
+}
+
+/// Writes the puzzle descriptions (see [`crate::puzzle::markdown_from_html`])
+/// of a day crate as `p1.md` and `p2.md`, and includes them as the docs of its parts.
+pub fn write_descriptions(dir: impl AsRef<Path>, descriptions: &[String]) -> io::Result<()> {
+    let dir = dir.as_ref();
+    for (part, description) in (1..).zip(descriptions) {
+        std::fs::write(dir.join(format!("p{}.md", part)), description)?;
+        let solver = dir.join("src").join(format!("p{}.rs", part));
+        let commented = format!("//#![doc = include_str!(\"../p{}.md\")]", part);
+        let contents = std::fs::read_to_string(&solver)?;
+        if contents.contains(&commented) {
+            std::fs::write(&solver, contents.replacen(&commented, &commented[2..], 1))?;
+        }
+    }
+    Ok(())
+}
+
+fn edit(path: &Path, change: impl FnOnce(&str) -> Option<String>) -> io::Result<String> {
+    change(&std::fs::read_to_string(path)?).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "{}: found no days to register the new day next to",
+                path.display()
+            ),
+        )
+    })
+}
+
+fn copy_template(template: &Path, dir: &Path, day: u8) -> io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    for entry in std::fs::read_dir(template)? {
+        let entry = entry?;
+        let target = dir.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_template(&entry.path(), &target, day)?;
+        } else {
+            let contents = std::fs::read_to_string(entry.path())?;
+            std::fs::write(target, instantiate(&contents, day))?;
+        }
+    }
+    Ok(())
+}
+
+fn instantiate(template: &str, day: u8) -> String {
+    template
+        .replace("\"day_\"", &format!("\"day{}\"", day))
+        .replace("register(_,", &format!("register({},", day))
+}
+
+/// The day of a line like `    day7::register(&mut registry);`, given what follows the day.
+fn day_of(line: &str, suffix: &str) -> Option<u8> {
+    line.trim()
+        .strip_prefix("day")?
+        .strip_suffix(suffix)?
+        .parse()
+        .ok()
+}
+
+/// Adds `line` among the lines of other days, in order of day.
+/// `None` if there are no such lines to go by.
+fn insert_day_line(
+    contents: &str,
+    day: u8,
+    day_of: impl Fn(&str) -> Option<u8>,
+    line: String,
+) -> Option<String> {
+    let lines = contents.lines().collect::<Vec<_>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, day_of(line)?)))
+        .collect::<Vec<_>>();
+    if days.iter().any(|(_, other)| *other == day) {
+        return Some(contents.to_string());
+    }
+    let at = match days.iter().find(|(_, other)| *other > day) {
+        Some((i, _)) => *i,
+        None => days.last()?.0 + 1,
+    };
+    let mut inserted = lines[..at].join("\n");
+    if at > 0 {
+        inserted.push('\n');
+    }
+    inserted.push_str(&line);
+    for line in &lines[at..] {
+        inserted.push('\n');
+        inserted.push_str(line);
+    }
+    if contents.ends_with('\n') {
+        inserted.push('\n');
+    }
+    Some(inserted)
+}
+
+/// Adds the day to the workspace members, which are listed five days to a line,
+/// and to the dependencies of the runner.
+fn register_manifest(manifest: &str, day: u8) -> Option<String> {
+    let start = manifest.find("members = [")?;
+    let end = start + manifest[start..].find(']')? + 1;
+    let mut others = Vec::new();
+    let mut days = Vec::new();
+    for member in manifest[start..end].split('"').skip(1).step_by(2) {
+        match member
+            .strip_prefix("day")
+            .and_then(|d| d.parse::<u8>().ok())
+        {
+            Some(day) => days.push(day),
+            None => others.push(format!("\"{}\"", member)),
+        }
+    }
+    days.push(day);
+    days.sort_unstable();
+    days.dedup();
+    let mut rows = others;
+    for chunk in days.chunk_by(|a, b| (a - 1) / 5 == (b - 1) / 5) {
+        let row = chunk.iter().map(|day| format!("\"day{}\"", day));
+        rows.push(row.collect::<Vec<_>>().join(", "));
+    }
+    let members = format!("members = [\n    {}]", rows.join(",\n    "));
+    let manifest = format!("{}{}{}", &manifest[..start], members, &manifest[end..]);
+    insert_day_line(
+        &manifest,
+        day,
+        day_of_dependency,
+        format!("day{} = {{ path = \"day{}\" }}", day, day),
+    )
+}
+
+/// The day of a line like `day7 = { path = "day7" }`.
+fn day_of_dependency(line: &str) -> Option<u8> {
+    line.strip_prefix("day")?
+        .split_once(" = { path = ")?
+        .0
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = "[workspace]
+members = [
+    \"util\",
+    \"day1\", \"day2\",
+    \"day17\", \"day18\",
+    \"day21\"]
+
+[dependencies]
+util = { workspace = true }
+day1 = { path = \"day1\" }
+day2 = { path = \"day2\" }
+day17 = { path = \"day17\" }
+day18 = { path = \"day18\" }
+day21 = { path = \"day21\" }
+";
+
+    #[test]
+    fn days_are_registered_in_order() {
+        let registered = register_manifest(MANIFEST, 16).unwrap();
+        assert!(registered.contains(
+            "members = [\n    \"util\",\n    \"day1\", \"day2\",\n    \
+             \"day16\", \"day17\", \"day18\",\n    \"day21\"]\n"
+        ));
+        assert!(registered.contains(
+            "day2 = { path = \"day2\" }\nday16 = { path = \"day16\" }\nday17 = { path = \"day17\" }\n"
+        ));
+        assert_eq!(register_manifest(&registered, 16).unwrap(), registered);
+        let lib = "pub fn registry() {\n    day1::register(&mut registry);\n    registry\n}\n";
+        let registered = insert_day_line(
+            lib,
+            23,
+            |line| day_of(line, "::register(&mut registry);"),
+            "    day23::register(&mut registry);".to_string(),
+        );
+        assert_eq!(
+            registered.unwrap(),
+            "pub fn registry() {\n    day1::register(&mut registry);\n    \
+             day23::register(&mut registry);\n    registry\n}\n"
+        );
+    }
+
+    #[test]
+    fn template_is_instantiated() {
+        let workspace =
+            std::env::temp_dir().join(format!("aoc-scaffold-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&workspace);
+        let template = workspace.join("day_template").join("src");
+        std::fs::create_dir_all(&template).unwrap();
+        std::fs::create_dir_all(workspace.join("src")).unwrap();
+        std::fs::write(workspace.join("Cargo.toml"), MANIFEST).unwrap();
+        std::fs::write(
+            workspace.join("src").join("lib.rs"),
+            "    day1::register(&mut registry);\n",
+        )
+        .unwrap();
+        std::fs::write(
+            workspace.join("day_template").join("Cargo.toml"),
+            "name = \"day_\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            template.join("p1.rs"),
+            "//#![doc = include_str!(\"../p1.md\")]\nregister(_, 1);\n",
+        )
+        .unwrap();
+
+        let dir = new_day(&workspace, 20).unwrap();
+        assert_eq!(dir, workspace.join("day20"));
+        let read = |path: PathBuf| std::fs::read_to_string(path).unwrap();
+        assert_eq!(read(dir.join("Cargo.toml")), "name = \"day20\"\n");
+        write_descriptions(&dir, &["# Day 20".to_string()]).unwrap();
+        assert_eq!(
+            read(dir.join("src").join("p1.rs")),
+            "#![doc = include_str!(\"../p1.md\")]\nregister(20, 1);\n"
+        );
+        assert_eq!(read(dir.join("p1.md")), "# Day 20");
+        assert!(read(workspace.join("Cargo.toml")).contains("day20 = { path = \"day20\" }"));
+        assert_eq!(
+            read(workspace.join("src").join("lib.rs")),
+            "    day1::register(&mut registry);\n    day20::register(&mut registry);\n"
+        );
+        assert_eq!(
+            new_day(&workspace, 20).unwrap_err().kind(),
+            io::ErrorKind::AlreadyExists
+        );
+        std::fs::remove_dir_all(&workspace).unwrap();
+    }
+}
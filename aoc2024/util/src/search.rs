@@ -0,0 +1,360 @@
+//! Shortest paths over any graph that can list the neighbours of a state.
+//!
+//! Every search takes several starts, such that "closest of any of these" needs
+//! no extra virtual start state, and a goal predicate instead of a single goal.
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The states reachable in one step from a state, with the cost of that step.
+/// Implemented for closures, such that a search can borrow any local map.
+pub trait Neighbours<S, C> {
+    fn neighbours(&self, state: &S) -> impl Iterator<Item = (S, C)>;
+}
+impl<S, C, F, I> Neighbours<S, C> for F
+where
+    F: Fn(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+{
+    fn neighbours(&self, state: &S) -> impl Iterator<Item = (S, C)> {
+        self(state).into_iter()
+    }
+}
+
+/// Summable costs, where `Default` is no cost at all.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+/// A cheapest way to a goal, with both its start and goal in `states`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+impl<S, C> Path<S, C> {
+    pub fn start(&self) -> &S {
+        self.states.first().expect("paths contain their start")
+    }
+
+    pub fn goal(&self) -> &S {
+        self.states.last().expect("paths contain their goal")
+    }
+}
+
+struct Node<S, C> {
+    state: S,
+    cost: C,
+    /// Every predecessor on a cheapest known way here.
+    parents: Vec<usize>,
+    closed: bool,
+}
+
+/// Every state seen by a search, numbered in order of discovery.
+struct Explored<S, C> {
+    nodes: Vec<Node<S, C>>,
+    index: HashMap<S, usize>,
+}
+impl<S: Clone + Eq + Hash, C: Cost> Explored<S, C> {
+    fn new() -> Self {
+        Explored {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    /// Offers a way to `state` costing `cost`, giving back its number if that
+    /// was the first way or a cheaper one. An equally cheap way is remembered
+    /// as another parent when `keep_ties` is set.
+    fn offer(
+        &mut self,
+        state: S,
+        cost: C,
+        parent: Option<usize>,
+        keep_ties: bool,
+    ) -> Option<usize> {
+        match self.index.entry(state) {
+            hash_map::Entry::Vacant(entry) => {
+                let number = self.nodes.len();
+                self.nodes.push(Node {
+                    state: entry.key().clone(),
+                    cost,
+                    parents: parent.into_iter().collect(),
+                    closed: false,
+                });
+                entry.insert(number);
+                Some(number)
+            }
+            hash_map::Entry::Occupied(entry) => {
+                let node = &mut self.nodes[*entry.get()];
+                if cost == node.cost && keep_ties {
+                    node.parents.extend(parent);
+                }
+                if node.closed || cost >= node.cost {
+                    return None;
+                }
+                node.cost = cost;
+                node.parents.clear();
+                node.parents.extend(parent);
+                Some(*entry.get())
+            }
+        }
+    }
+
+    /// The path to `node` through its first parents.
+    fn path(&self, mut node: usize) -> Path<S, C> {
+        let cost = self.nodes[node].cost;
+        let mut states = vec![self.nodes[node].state.clone()];
+        while let Some(&parent) = self.nodes[node].parents.first() {
+            states.push(self.nodes[parent].state.clone());
+            node = parent;
+        }
+        states.reverse();
+        Path { cost, states }
+    }
+}
+
+/// Fewest steps from any of `starts` to a state matching `is_goal`.
+/// Step costs are ignored, the path cost is its amount of steps.
+pub fn bfs<S: Clone + Eq + Hash, C>(
+    graph: &impl Neighbours<S, C>,
+    starts: impl IntoIterator<Item = S>,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>> {
+    let mut explored = Explored::new();
+    let mut frontier = VecDeque::new();
+    for start in starts {
+        frontier.extend(explored.offer(start, 0, None, false));
+    }
+    while let Some(current) = frontier.pop_front() {
+        let state = explored.nodes[current].state.clone();
+        if is_goal(&state) {
+            return Some(explored.path(current));
+        }
+        let steps = explored.nodes[current].cost + 1;
+        for (next, _) in graph.neighbours(&state) {
+            frontier.extend(explored.offer(next, steps, Some(current), false));
+        }
+    }
+    None
+}
+
+/// Cheapest path from any of `starts` to a state matching `is_goal`.
+pub fn dijkstra<S: Clone + Eq + Hash, C: Cost>(
+    graph: &impl Neighbours<S, C>,
+    starts: impl IntoIterator<Item = S>,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>> {
+    astar(graph, starts, is_goal, |_| C::default())
+}
+
+/// Cheapest path from any of `starts` to a state matching `is_goal`,
+/// exploring states closest to a goal by `heuristic` first.
+/// The heuristic must never overestimate the remaining cost, and never drop
+/// by more than the cost of a step, or a more expensive path may be found.
+pub fn astar<S: Clone + Eq + Hash, C: Cost>(
+    graph: &impl Neighbours<S, C>,
+    starts: impl IntoIterator<Item = S>,
+    mut is_goal: impl FnMut(&S) -> bool,
+    mut heuristic: impl FnMut(&S) -> C,
+) -> Option<Path<S, C>> {
+    let mut explored = Explored::new();
+    let mut frontier = BinaryHeap::new();
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(node) = explored.offer(start, C::default(), None, false) {
+            frontier.push(Reverse((estimate, node)));
+        }
+    }
+    while let Some(Reverse((_, current))) = frontier.pop() {
+        if std::mem::replace(&mut explored.nodes[current].closed, true) {
+            continue; // was queued again when a cheaper way turned up
+        }
+        let state = explored.nodes[current].state.clone();
+        if is_goal(&state) {
+            return Some(explored.path(current));
+        }
+        let cost = explored.nodes[current].cost;
+        for (next, step) in graph.neighbours(&state) {
+            let estimate = heuristic(&next);
+            if let Some(node) = explored.offer(next, cost + step, Some(current), false) {
+                frontier.push(Reverse((cost + step + estimate, node)));
+            }
+        }
+    }
+    None
+}
+
+/// Every cheapest path from any of `starts` to any state matching `is_goal`.
+pub fn dijkstra_all<S: Clone + Eq + Hash, C: Cost>(
+    graph: &impl Neighbours<S, C>,
+    starts: impl IntoIterator<Item = S>,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<AllPaths<S, C>> {
+    let mut explored = Explored::new();
+    let mut frontier = BinaryHeap::new();
+    for start in starts {
+        if let Some(node) = explored.offer(start, C::default(), None, true) {
+            frontier.push(Reverse((C::default(), node)));
+        }
+    }
+    let mut goals: Vec<usize> = Vec::new();
+    while let Some(Reverse((cost, current))) = frontier.pop() {
+        if let Some(&goal) = goals.first() {
+            if cost > explored.nodes[goal].cost {
+                break;
+            }
+        }
+        if std::mem::replace(&mut explored.nodes[current].closed, true) {
+            continue;
+        }
+        let state = explored.nodes[current].state.clone();
+        if is_goal(&state) {
+            goals.push(current);
+            continue;
+        }
+        for (next, step) in graph.neighbours(&state) {
+            if let Some(node) = explored.offer(next, cost + step, Some(current), true) {
+                frontier.push(Reverse((cost + step, node)));
+            }
+        }
+    }
+    let cost = explored.nodes[*goals.first()?].cost;
+    Some(AllPaths {
+        cost,
+        nodes: explored
+            .nodes
+            .into_iter()
+            .map(|node| (node.state, node.parents))
+            .collect(),
+        goals,
+    })
+}
+
+/// Every equally cheap way to the closest goals, sharing their common parts.
+pub struct AllPaths<S, C> {
+    pub cost: C,
+    nodes: Vec<(S, Vec<usize>)>,
+    goals: Vec<usize>,
+}
+impl<S: Clone + Eq + Hash, C> AllPaths<S, C> {
+    /// Every goal reached at the lowest cost.
+    pub fn goals(&self) -> impl Iterator<Item = &S> {
+        self.goals.iter().map(|&goal| &self.nodes[goal].0)
+    }
+
+    /// Every state on at least one of the paths.
+    pub fn states(&self) -> HashSet<&S> {
+        let mut seen = HashSet::new();
+        let mut unvisited = self.goals.clone();
+        while let Some(node) = unvisited.pop() {
+            if seen.insert(node) {
+                unvisited.extend(&self.nodes[node].1);
+            }
+        }
+        seen.into_iter().map(|node| &self.nodes[node].0).collect()
+    }
+
+    /// Every path, from start to goal.
+    /// There may be exponentially many, prefer `states` when that is enough.
+    pub fn paths(&self) -> Vec<Vec<S>> {
+        let mut paths = Vec::new();
+        let mut unfinished = self
+            .goals
+            .iter()
+            .map(|&goal| vec![goal])
+            .collect::<Vec<_>>();
+        while let Some(backwards) = unfinished.pop() {
+            let parents = &self.nodes[*backwards.last().expect("non-empty")].1;
+            if parents.is_empty() {
+                let path = backwards
+                    .iter()
+                    .rev()
+                    .map(|&node| self.nodes[node].0.clone());
+                paths.push(path.collect());
+            }
+            for &parent in parents {
+                let mut longer = backwards.clone();
+                longer.push(parent);
+                unfinished.push(longer);
+            }
+        }
+        paths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Coord, Grid};
+
+    const MAZE: &str = "\
+S..#...
+.#.#.#.
+.#...#.
+.####..
+......G";
+
+    fn maze() -> (Grid<char>, Coord, Coord) {
+        let grid = MAZE.parse::<Grid<char>>().unwrap();
+        let start = grid.positions(|c| *c == 'S').next().unwrap();
+        let goal = grid.positions(|c| *c == 'G').next().unwrap();
+        (grid, start, goal)
+    }
+
+    #[test]
+    fn searches_agree_on_unit_costs() {
+        let (grid, start, goal) = maze();
+        let open = |coord: &Coord| {
+            grid.neighbours4(*coord)
+                .filter(|next| grid[*next] != '#')
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let bfs = bfs(&open, [start], |c| *c == goal).unwrap();
+        let dijkstra = dijkstra(&open, [start], |c| *c == goal).unwrap();
+        let manhattan = |c: &Coord| goal.x.abs_diff(c.x) + goal.y.abs_diff(c.y);
+        let astar = astar(&open, [start], |c| *c == goal, manhattan).unwrap();
+        assert_eq!(bfs.cost, 10);
+        assert_eq!((dijkstra.cost, astar.cost), (10, 10));
+        assert_eq!((bfs.start(), bfs.goal()), (&start, &goal));
+        assert_eq!(bfs.states.len(), 11);
+        let all = dijkstra_all(&open, [start], |c| *c == goal).unwrap();
+        assert_eq!(all.cost, 10);
+        assert_eq!(all.paths(), [bfs.states]);
+    }
+
+    #[test]
+    fn all_shortest_paths_are_kept() {
+        let grid = "...\n.#.\n...".parse::<Grid<char>>().unwrap();
+        let open = |coord: &Coord| {
+            grid.neighbours4(*coord)
+                .filter(|next| grid[*next] != '#')
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let corners = [Coord::new(2, 0), Coord::new(0, 2)];
+        let all = dijkstra_all(&open, [Coord::new(0, 0)], |c| *c == Coord::new(2, 2)).unwrap();
+        assert_eq!((all.cost, all.paths().len(), all.states().len()), (4, 2, 8));
+        let all = dijkstra_all(&open, [Coord::new(0, 0)], |c| corners.contains(c)).unwrap();
+        assert_eq!((all.cost, all.goals().count()), (2, 2));
+    }
+
+    #[test]
+    fn costs_and_sources_are_respected() {
+        // going right is cheap, going down is expensive
+        let graph = |&(x, y): &(u8, u8)| {
+            let right = (x < 3).then_some(((x + 1, y), 1));
+            let down = (y < 3).then_some(((x, y + 1), 5));
+            right.into_iter().chain(down)
+        };
+        let path = dijkstra(&graph, [(0, 0)], |&(x, y)| x == 3 || y == 3).unwrap();
+        assert_eq!((path.cost, path.goal()), (3, &(3, 0)));
+        let path = dijkstra(&graph, [(0, 0), (0, 2)], |&(_, y)| y == 3).unwrap();
+        assert_eq!((path.cost, path.start()), (5, &(0, 2)));
+        assert!(bfs(&graph, [(0, 0)], |&(x, _)| x > 3).is_none());
+    }
+}
@@ -0,0 +1,197 @@
+use std::{
+    error::Error,
+    fmt::{Debug, Display, Formatter},
+};
+
+use winnow::{
+    error::{ContextError, ErrMode, StrContext},
+    Parser,
+};
+
+use crate::Normalize;
+
+/// A day that parses its input once, into a model both parts can reuse.
+pub trait Solution {
+    /// The parsed model, which may borrow from the input.
+    type Parsed<'i>;
+    /// Cleanups to apply to the input before it reaches [`Solution::parse`].
+    const NORMALIZE: Normalize = Normalize::NONE;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+    fn part1(parsed: &Self::Parsed<'_>) -> impl Display;
+    fn part2(parsed: &Self::Parsed<'_>) -> impl Display;
+}
+
+/// Input that could not be turned into a day's model.
+/// When it is known where parsing stopped, it is reported like a compiler would:
+/// ```text
+/// at line 2, column 4: unexpected 'x'
+///   while parsing line with two numbers
+///   |
+/// 2 | 12 x4
+///   |    ^
+/// ```
+pub struct ParseError {
+    message: String,
+    /// Outermost last, as winnow adds them while unwinding.
+    labels: Vec<&'static str>,
+    location: Option<Location>,
+}
+struct Location {
+    line: usize,
+    column: usize,
+    text: String,
+}
+impl ParseError {
+    pub fn new(message: impl Display) -> Self {
+        ParseError {
+            message: message.to_string(),
+            labels: Vec::new(),
+            location: None,
+        }
+    }
+
+    /// Points at the character at byte `offset` of `input`.
+    pub fn at(input: &str, offset: usize, message: impl Display) -> Self {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |newline| newline + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |newline| offset + newline);
+        let location = Location {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        };
+        ParseError {
+            location: Some(location),
+            ..ParseError::new(message)
+        }
+    }
+
+    /// Reports `error` at byte `offset` of `input`, with its context labels.
+    pub fn from_context(input: &str, offset: usize, error: &ContextError) -> Self {
+        let mut labels = Vec::new();
+        let mut expected = Vec::new();
+        for context in error.context() {
+            match context {
+                StrContext::Label(label) => labels.push(*label),
+                StrContext::Expected(value) => expected.push(value.to_string()),
+                _ => {}
+            }
+        }
+        let message = match (expected.is_empty(), error.cause()) {
+            (false, _) => format!("expected {}", expected.join(" or ")),
+            (true, Some(cause)) => cause.to_string(),
+            (true, None) => match input[offset.min(input.len())..].chars().next() {
+                Some(found) => format!("unexpected {:?}", found),
+                None => "unexpected end of input".to_string(),
+            },
+        };
+        ParseError {
+            labels,
+            ..ParseError::at(input, offset, message)
+        }
+    }
+}
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Some(location) = &self.location else {
+            return write!(f, "{}", self.message);
+        };
+        write!(
+            f,
+            "at line {}, column {}: {}",
+            location.line, location.column, self.message
+        )?;
+        for label in &self.labels {
+            write!(f, "\n  while parsing {}", label)?;
+        }
+        let gutter = " ".repeat(location.line.to_string().len());
+        let before = location.text.chars().take(location.column - 1);
+        let indent = before
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+        write!(
+            f,
+            "\n{} |\n{} | {}\n{} | {}^",
+            gutter, location.line, location.text, gutter, indent
+        )
+    }
+}
+/// The report itself, such that `.expect()` on a parse shows where it failed.
+impl Debug for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+impl Error for ParseError {}
+
+/// From `Parser::parse`, which knows where in the input it failed.
+impl From<winnow::error::ParseError<&str, ContextError>> for ParseError {
+    fn from(e: winnow::error::ParseError<&str, ContextError>) -> Self {
+        ParseError::from_context(e.input(), e.offset(), e.inner())
+    }
+}
+
+/// From `Parser::parse_next`, which does not know the whole input.
+/// Prefer `ParseReport::parse_report`, which does.
+impl From<ErrMode<ContextError>> for ParseError {
+    fn from(e: ErrMode<ContextError>) -> Self {
+        ParseError::new(e)
+    }
+}
+
+/// Parsing the start of a whole input, with failures located in it.
+pub trait ParseReport<'i, O> {
+    /// Like `parse_next`, which leaves trailing input alone.
+    fn parse_report(&mut self, input: &'i str) -> Result<O, ParseError>;
+}
+impl<'i, O, P: Parser<&'i str, O, ContextError>> ParseReport<'i, O> for P {
+    fn parse_report(&mut self, input: &'i str) -> Result<O, ParseError> {
+        let mut remaining = input;
+        self.parse_next(&mut remaining).map_err(|e| {
+            let offset = input.len() - remaining.len();
+            match e.into_inner() {
+                Some(error) => ParseError::from_context(input, offset, &error),
+                None => ParseError::at(input, offset, "incomplete input"),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use winnow::{
+        ascii::{dec_uint, line_ending, space1},
+        combinator::separated_pair,
+        error::StrContext,
+        ModalResult, Parser,
+    };
+
+    use super::*;
+
+    fn pair(input: &mut &str) -> ModalResult<(u32, u32)> {
+        separated_pair(dec_uint, space1, dec_uint)
+            .context(StrContext::Label("line with two numbers"))
+            .parse_next(input)
+    }
+
+    #[test]
+    fn reports_point_at_failure() {
+        let report = (pair, line_ending, pair)
+            .parse_report("1 2\r\n12 x4\r\n")
+            .unwrap_err()
+            .to_string();
+        let expected = "\
+at line 2, column 4: unexpected 'x'
+  while parsing line with two numbers
+  |
+2 | 12 x4
+  |    ^";
+        assert_eq!(report, expected);
+        assert_eq!(pair.parse_report("1 2\n3 4").unwrap(), (1, 2));
+    }
+}
@@ -0,0 +1,263 @@
+//! SVG pictures of grid states, for write-ups. A [`Picture`] starts from the
+//! text a grid renders to, one cell per character in its [`viz::color`],
+//! and takes fills per cell, paths through cell centers and labels on top.
+//! Solvers hand pictures to [`export`], which only draws them while exporting
+//! is enabled (see [`export_to`]), like [`viz::frame`].
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    fs,
+    path::PathBuf,
+    sync::{Mutex, PoisonError},
+};
+
+use crate::{grid::Coord, viz};
+
+/// Pixels per cell.
+pub const CELL: usize = 16;
+
+struct Exports {
+    dir: PathBuf,
+    written: Vec<PathBuf>,
+}
+static EXPORTS: Mutex<Option<Exports>> = Mutex::new(None);
+
+/// Writes the pictures of later [`export`] calls into `dir`.
+pub fn export_to(dir: impl Into<PathBuf>) {
+    *EXPORTS.lock().unwrap_or_else(PoisonError::into_inner) = Some(Exports {
+        dir: dir.into(),
+        written: Vec::new(),
+    });
+}
+
+/// Writes the picture `draw` gives to `name.svg`, if exporting is enabled.
+/// Failures are logged as warnings, as pictures are only a side product of solving.
+pub fn export(name: &str, draw: impl FnOnce() -> Picture) {
+    let mut exports = EXPORTS.lock().unwrap_or_else(PoisonError::into_inner);
+    let Some(exports) = exports.as_mut() else {
+        return;
+    };
+    let path = exports.dir.join(format!("{}.svg", name));
+    match fs::create_dir_all(&exports.dir).and_then(|()| fs::write(&path, draw().to_string())) {
+        Ok(()) => exports.written.push(path),
+        Err(e) => log::warn!("Could not write {:?}: {}", path, e),
+    }
+}
+
+/// Stops exporting, giving back the files written since [`export_to`].
+pub fn finish() -> Vec<PathBuf> {
+    EXPORTS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take()
+        .map_or_else(Vec::new, |exports| exports.written)
+}
+
+/// Colors that are far apart for neighbouring `n`, to tell regions apart.
+pub fn distinct(n: usize) -> [u8; 3] {
+    // golden angle steps around the hue circle
+    let hue = (n as f64 * 137.508) % 360.0;
+    let channel = |offset: f64| {
+        let k = (offset + hue / 30.0) % 12.0;
+        let value = 0.55 - 0.35 * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0);
+        (value * 255.0).round() as u8
+    };
+    [channel(0.0), channel(8.0), channel(4.0)]
+}
+
+/// A grid of characters with overlays, rendering as an SVG document.
+#[derive(Debug, Clone)]
+pub struct Picture {
+    rows: Vec<Vec<char>>,
+    fills: HashMap<Coord, [u8; 3]>,
+    paths: Vec<(Vec<Coord>, [u8; 3])>,
+    labels: Vec<(Coord, String, [u8; 3])>,
+}
+impl Picture {
+    /// One cell per character of every line of `text`, ignoring color escapes.
+    pub fn from_text(text: impl Display) -> Self {
+        let rows = viz::strip_escapes(&text.to_string())
+            .lines()
+            .map(|line| line.chars().collect())
+            .collect();
+        Picture {
+            rows,
+            fills: HashMap::new(),
+            paths: Vec::new(),
+            labels: Vec::new(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.rows.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Fills the cell at `coord` instead of in the color of its character.
+    pub fn fill(&mut self, coord: Coord, color: [u8; 3]) -> &mut Self {
+        self.fills.insert(coord, color);
+        self
+    }
+
+    /// Draws a line through the centers of `coords`, in order.
+    pub fn path(&mut self, coords: impl IntoIterator<Item = Coord>, color: [u8; 3]) -> &mut Self {
+        self.paths.push((coords.into_iter().collect(), color));
+        self
+    }
+
+    /// Writes `text` centered on the cell at `coord`, over the cells and paths.
+    pub fn label(&mut self, coord: Coord, text: impl Display, color: [u8; 3]) -> &mut Self {
+        self.labels.push((coord, text.to_string(), color));
+        self
+    }
+}
+impl Display for Picture {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (width, height) = (self.width() * CELL, self.height() * CELL);
+        let center = |n: usize| n * CELL + CELL / 2;
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}" font-family="monospace" font-size="{}" text-anchor="middle" dominant-baseline="central">"#,
+            width,
+            height,
+            width,
+            height,
+            CELL * 3 / 4
+        )?;
+        writeln!(
+            f,
+            r#"<rect width="{}" height="{}" fill="{}"/>"#,
+            width,
+            height,
+            Hex(viz::color(' '))
+        )?;
+        for (y, row) in self.rows.iter().enumerate() {
+            for (x, &c) in row.iter().enumerate() {
+                let fill = self
+                    .fills
+                    .get(&Coord { x, y })
+                    .copied()
+                    .unwrap_or_else(|| viz::color(c));
+                writeln!(
+                    f,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                    x * CELL,
+                    y * CELL,
+                    CELL,
+                    CELL,
+                    Hex(fill)
+                )?;
+                if !c.is_whitespace() {
+                    writeln!(
+                        f,
+                        r#"<text x="{}" y="{}" fill="{}">{}</text>"#,
+                        center(x),
+                        center(y),
+                        Hex(contrast(fill)),
+                        Escaped(&c.to_string())
+                    )?;
+                }
+            }
+        }
+        for (coords, color) in &self.paths {
+            let points = coords
+                .iter()
+                .map(|coord| format!("{},{}", center(coord.x), center(coord.y)))
+                .collect::<Vec<_>>()
+                .join(" ");
+            writeln!(
+                f,
+                r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{}" stroke-linecap="round" stroke-linejoin="round" opacity="0.8"/>"#,
+                points,
+                Hex(*color),
+                CELL / 4
+            )?;
+        }
+        for (coord, text, color) in &self.labels {
+            writeln!(
+                f,
+                r#"<text x="{}" y="{}" fill="{}" stroke="{}" stroke-width="3" paint-order="stroke" font-weight="bold">{}</text>"#,
+                center(coord.x),
+                center(coord.y),
+                Hex(*color),
+                Hex(contrast(*color)),
+                Escaped(text)
+            )?;
+        }
+        write!(f, "</svg>")
+    }
+}
+
+/// Black or white, whichever reads better on `color`.
+fn contrast([r, g, b]: [u8; 3]) -> [u8; 3] {
+    let luma = 299 * r as u32 + 587 * g as u32 + 114 * b as u32;
+    if luma > 128_000 {
+        [0, 0, 0]
+    } else {
+        [255, 255, 255]
+    }
+}
+
+struct Hex([u8; 3]);
+impl Display for Hex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let [r, g, b] = self.0;
+        write!(f, "#{:02x}{:02x}{:02x}", r, g, b)
+    }
+}
+
+struct Escaped<'s>(&'s str);
+impl Display for Escaped<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '&' => write!(f, "&amp;")?,
+                '<' => write!(f, "&lt;")?,
+                '>' => write!(f, "&gt;")?,
+                '"' => write!(f, "&quot;")?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pictures_render_overlays() {
+        let mut picture = Picture::from_text("#.<\n\x1b[34m.\x1b[0m@.");
+        assert_eq!((picture.width(), picture.height()), (3, 2));
+        picture
+            .fill(Coord::new(1, 0), [255, 0, 0])
+            .path([Coord::new(0, 1), Coord::new(2, 1)], [0, 0, 255])
+            .label(Coord::new(1, 1), "a&b", [255, 255, 0]);
+        let svg = picture.to_string();
+        assert!(svg.starts_with("<svg ") && svg.ends_with("</svg>"));
+        assert_eq!(svg.matches("<rect ").count(), 1 + 6);
+        assert!(svg.contains(r##"<rect x="16" y="0" width="16" height="16" fill="#ff0000"/>"##));
+        assert!(svg.contains(r##"<text x="40" y="8" fill="#ffffff">&lt;</text>"##));
+        assert!(svg.contains(r#"<polyline points="8,24 40,24""#));
+        assert!(svg.contains(">a&amp;b</text>"));
+        assert!(!svg.contains('\x1b'));
+        assert_ne!(distinct(0), distinct(1));
+    }
+
+    #[test]
+    fn exports_go_to_their_dir() {
+        let dir = std::env::temp_dir().join(format!("aoc-svg-test-{}", std::process::id()));
+        export("skipped", || panic!("not exporting, so not drawn"));
+        export_to(&dir);
+        export("grid", || Picture::from_text("#."));
+        let written = finish();
+        assert_eq!(written, [dir.join("grid.svg")]);
+        assert!(fs::read_to_string(&written[0]).unwrap().contains("<svg "));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,140 @@
+//! Leveled logging for solvers, through the `log` macros (`log::debug!`,
+//! `log::trace!`, ...), with a level per module. Messages are only formatted
+//! when their level is enabled, so renders of whole grids can stay in hot paths
+//! as long as they are logged at `trace`.
+
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Read when no filter is given on the command line.
+pub const ENV: &str = "AOC_LOG";
+/// Only warnings and errors, such that real inputs run without stderr output.
+pub const DEFAULT: &str = "warn";
+
+/// Levels per module, like `info,day12=trace,day17::p1=debug`:
+/// a default level, then `module=level` overrides,
+/// where a module also covers the modules within it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    default: LevelFilter,
+    modules: Vec<(String, LevelFilter)>,
+}
+impl Filter {
+    /// The level of the innermost module given for `target`.
+    pub fn level(&self, target: &str) -> LevelFilter {
+        self.modules
+            .iter()
+            .filter(|(module, _)| {
+                target
+                    .strip_prefix(module.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .max_by_key(|(module, _)| module.len())
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    /// The most verbose level of any module.
+    pub fn max_level(&self) -> LevelFilter {
+        self.modules
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Ord::max)
+    }
+}
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Filter {
+            default: LevelFilter::Warn,
+            modules: Vec::new(),
+        };
+        let level = |level: &str| {
+            level
+                .parse::<LevelFilter>()
+                .map_err(|_| format!("'{}' is not a log level", level))
+        };
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((module, module_level)) => filter
+                    .modules
+                    .push((module.trim().to_string(), level(module_level.trim())?)),
+                None => filter.default = level(directive)?,
+            }
+        }
+        Ok(filter)
+    }
+}
+impl Display for Filter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.default.as_str().to_lowercase())?;
+        for (module, level) in &self.modules {
+            write!(f, ",{}={}", module, level.as_str().to_lowercase())?;
+        }
+        Ok(())
+    }
+}
+
+struct Logger(Filter);
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.0.level(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!(
+                "{:<5} {}: {}",
+                record.level(),
+                record.target(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Logs to stderr with the levels of `filter`, or else of [`ENV`], or else [`DEFAULT`].
+/// Only the first call in a process takes effect.
+pub fn init(filter: Option<Filter>) -> Result<(), String> {
+    let filter = match filter {
+        Some(filter) => filter,
+        None => std::env::var(ENV)
+            .as_deref()
+            .unwrap_or(DEFAULT)
+            .parse()
+            .map_err(|e| format!("{}: {}", ENV, e))?,
+    };
+    let max_level = filter.max_level();
+    log::set_boxed_logger(Box::new(Logger(filter))).map_err(|e| e.to_string())?;
+    log::set_max_level(max_level);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn innermost_module_decides() {
+        let filter = "info, day12=trace,day17::p1=debug"
+            .parse::<Filter>()
+            .unwrap();
+        assert_eq!(filter.level("day12::p1_garden"), LevelFilter::Trace);
+        assert_eq!(filter.level("day17::p1"), LevelFilter::Debug);
+        assert_eq!(filter.level("day17::p2"), LevelFilter::Info);
+        assert_eq!(filter.level("day123"), LevelFilter::Info);
+        assert_eq!(filter.max_level(), LevelFilter::Trace);
+        assert_eq!(filter.to_string(), "info,day12=trace,day17::p1=debug");
+        assert_eq!(
+            DEFAULT.parse::<Filter>().unwrap().max_level(),
+            LevelFilter::Warn
+        );
+        assert!("day12=loud".parse::<Filter>().is_err());
+    }
+}
@@ -0,0 +1,374 @@
+//! Frames of simulations, to watch them instead of scrolling through logs.
+//! Solvers push their state with [`frame`], which only renders it while a
+//! recording runs (see [`record`]), so the calls can stay in hot paths.
+//! Recorded frames are replayed in a terminal, or encoded to an animated GIF
+//! or APNG, with every character of a frame as a square of its [`color`].
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, PoisonError,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Frames kept of a single recording, see [`Recorder`].
+pub const CAPACITY: usize = 1024;
+/// Pixels per character in pictures.
+pub const SCALE: u16 = 4;
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static RECORDER: Mutex<Option<Recorder>> = Mutex::new(None);
+
+/// Starts recording the frames pushed by solvers, dropping any earlier recording.
+pub fn record(capacity: usize) {
+    *RECORDER.lock().unwrap_or_else(PoisonError::into_inner) = Some(Recorder::new(capacity));
+    RECORDING.store(true, Ordering::Relaxed);
+}
+
+/// Pushes a frame of `state` into the running recording, if any.
+pub fn frame(state: impl Display) {
+    if RECORDING.load(Ordering::Relaxed) {
+        if let Some(recorder) = RECORDER
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+        {
+            recorder.push(state);
+        }
+    }
+}
+
+/// Stops the running recording and gives back its frames.
+pub fn finish() -> Option<Recorder> {
+    RECORDING.store(false, Ordering::Relaxed);
+    RECORDER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take()
+}
+
+/// Rendered frames, from the first to the last pushed one.
+/// When more than `capacity` are pushed, every other frame is dropped,
+/// and from then on only every other frame is rendered.
+#[derive(Debug)]
+pub struct Recorder {
+    frames: Vec<String>,
+    capacity: usize,
+    pushed: usize,
+    stride: usize,
+}
+impl Recorder {
+    pub fn new(capacity: usize) -> Self {
+        Recorder {
+            frames: Vec::new(),
+            capacity: capacity.max(2),
+            pushed: 0,
+            stride: 1,
+        }
+    }
+
+    pub fn push(&mut self, frame: impl Display) {
+        if self.pushed.is_multiple_of(self.stride) && self.frames.len() == self.capacity {
+            let mut index = 0;
+            self.frames.retain(|_| {
+                index += 1;
+                index % 2 == 1
+            });
+            self.stride *= 2;
+        }
+        if self.pushed.is_multiple_of(self.stride) {
+            self.frames.push(frame.to_string());
+        }
+        self.pushed += 1;
+    }
+
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    /// How many pushed frames there are per kept frame.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+}
+
+/// Where to play frames: `-` for the terminal (on stderr, apart from answers), or a `.gif`, `.png` or `.apng` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Output {
+    Terminal,
+    Gif(PathBuf),
+    Apng(PathBuf),
+}
+impl FromStr for Output {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let path = PathBuf::from(s);
+        match path.extension().and_then(|extension| extension.to_str()) {
+            _ if s == "-" => Ok(Output::Terminal),
+            Some("gif") => Ok(Output::Gif(path)),
+            Some("png" | "apng") => Ok(Output::Apng(path)),
+            _ => Err(format!("'{}' is not -, or a .gif, .png or .apng file", s)),
+        }
+    }
+}
+impl Output {
+    /// Shows `fps` frames per second.
+    pub fn play(&self, frames: &[String], fps: u32) -> io::Result<()> {
+        match self {
+            Output::Terminal => replay(frames, io::stderr().lock(), fps),
+            Output::Gif(path) => write_gif(frames, create(path)?, fps),
+            Output::Apng(path) => write_apng(frames, create(path)?, fps),
+        }
+    }
+}
+
+fn create(path: &Path) -> io::Result<BufWriter<File>> {
+    File::create(path).map(BufWriter::new)
+}
+
+/// Draws every frame over the last one, below what was already written.
+pub fn replay(frames: &[String], mut out: impl Write, fps: u32) -> io::Result<()> {
+    let delay = Duration::from_secs(1) / fps.max(1);
+    write!(out, "\x1b[?25l")?;
+    let mut drawn = 0;
+    for frame in frames {
+        let start = Instant::now();
+        if drawn > 0 {
+            // back to the first line of the last frame
+            write!(out, "\x1b[{}A", drawn)?;
+        }
+        drawn = 0;
+        for line in frame.lines() {
+            // clear what is left of the longer lines of the last frame
+            write!(out, "\r{}\x1b[K\n", line)?;
+            drawn += 1;
+        }
+        write!(out, "\x1b[J")?;
+        out.flush()?;
+        thread::sleep(delay.saturating_sub(start.elapsed()));
+    }
+    write!(out, "\x1b[?25h")?;
+    out.flush()
+}
+
+/// Loops forever. GIF delays are in hundredths of a second,
+/// so frame rates above 50 are slowed down to it.
+pub fn write_gif(frames: &[String], out: impl Write, fps: u32) -> io::Result<()> {
+    let pictures = Pictures::new(frames)?;
+    let mut encoder = gif::Encoder::new(out, pictures.width, pictures.height, &pictures.palette)
+        .map_err(io::Error::other)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(io::Error::other)?;
+    let delay = (100 / fps.max(1)).clamp(2, u16::MAX as u32) as u16;
+    for pixels in pictures.pixels() {
+        let mut frame =
+            gif::Frame::from_indexed_pixels(pictures.width, pictures.height, pixels, None);
+        frame.delay = delay;
+        encoder.write_frame(&frame).map_err(io::Error::other)?;
+    }
+    Ok(())
+}
+
+/// Loops forever.
+pub fn write_apng(frames: &[String], out: impl Write, fps: u32) -> io::Result<()> {
+    let pictures = Pictures::new(frames)?;
+    let mut encoder = png::Encoder::new(out, pictures.width as u32, pictures.height as u32);
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_palette(pictures.palette.clone());
+    encoder
+        .set_animated(frames.len() as u32, 0)
+        .map_err(io::Error::other)?;
+    encoder
+        .set_frame_delay(1, fps.clamp(1, u16::MAX as u32) as u16)
+        .map_err(io::Error::other)?;
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    for pixels in pictures.pixels() {
+        writer.write_image_data(&pixels).map_err(io::Error::other)?;
+    }
+    writer.finish().map_err(io::Error::other)
+}
+
+/// The color of a character: dark for empty cells, light for walls,
+/// brighter greens for higher digits, and fixed colors for the symbols
+/// of guards, robots, boxes and visited cells.
+pub fn color(c: char) -> [u8; 3] {
+    match c {
+        ' ' | '.' => [16, 16, 24],
+        '#' => [200, 200, 200],
+        '@' => [255, 215, 0],
+        '^' | 'v' | '<' | '>' => [230, 60, 60],
+        'X' => [70, 130, 230],
+        'O' | '[' | ']' => [180, 120, 60],
+        _ => match c.to_digit(16) {
+            Some(digit) => [40, 95 + 10 * digit as u8, 60],
+            None => {
+                // spread other characters over the colors, but keep them bright
+                let n = c as u32;
+                [
+                    (96 + n * 71 % 160) as u8,
+                    (96 + n * 113 % 160) as u8,
+                    (96 + n * 37 % 160) as u8,
+                ]
+            }
+        },
+    }
+}
+
+/// Frames as indices into a palette of the colors of their characters,
+/// all padded with empty cells to the largest frame.
+struct Pictures {
+    frames: Vec<Vec<Vec<char>>>,
+    width: u16,
+    height: u16,
+    palette: Vec<u8>,
+    indices: HashMap<char, u8>,
+}
+impl Pictures {
+    fn new(frames: &[String]) -> io::Result<Self> {
+        if frames.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "no frames"));
+        }
+        let frames = frames
+            .iter()
+            .map(|frame| {
+                strip_escapes(frame)
+                    .lines()
+                    .map(|line| line.chars().collect())
+                    .collect::<Vec<Vec<char>>>()
+            })
+            .collect::<Vec<_>>();
+        let columns = frames
+            .iter()
+            .flatten()
+            .map(Vec::len)
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let rows = frames.iter().map(Vec::len).max().unwrap_or(0).max(1);
+        let pixels = |cells: usize| {
+            u16::try_from(cells * SCALE as usize).map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{}x{} cells do not fit in a picture", columns, rows),
+                )
+            })
+        };
+        let (width, height) = (pixels(columns)?, pixels(rows)?);
+        // the empty color goes first, such that padding is index 0
+        let mut palette = color(' ').to_vec();
+        let mut indices = HashMap::from([(' ', 0)]);
+        for &c in frames.iter().flatten().flatten() {
+            let next = indices.len();
+            // beyond 256 colors, the rest shares the last one
+            if next < 256 && !indices.contains_key(&c) {
+                indices.insert(c, next as u8);
+                palette.extend(color(c));
+            }
+        }
+        Ok(Pictures {
+            frames,
+            width,
+            height,
+            palette,
+            indices,
+        })
+    }
+
+    fn pixels(&self) -> impl Iterator<Item = Vec<u8>> + '_ {
+        let scale = SCALE as usize;
+        let columns = self.width as usize / scale;
+        let rows = self.height as usize / scale;
+        self.frames.iter().map(move |lines| {
+            let mut pixels = Vec::with_capacity(self.width as usize * self.height as usize);
+            for row in 0..rows {
+                let line = lines.get(row).map_or(&[][..], Vec::as_slice);
+                let cells = (0..columns)
+                    .map(|column| {
+                        line.get(column)
+                            .map_or(0, |c| *self.indices.get(c).unwrap_or(&255))
+                    })
+                    .collect::<Vec<_>>();
+                for _ in 0..scale {
+                    for &cell in &cells {
+                        pixels.extend(std::iter::repeat_n(cell, scale));
+                    }
+                }
+            }
+            pixels
+        })
+    }
+}
+
+/// Removes ANSI escape sequences (like colors), which have no cells.
+pub(crate) fn strip_escapes(frame: &str) -> String {
+    let mut plain = String::with_capacity(frame.len());
+    let mut chars = frame.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            }
+        } else {
+            plain.push(c);
+        }
+    }
+    plain
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_recordings_keep_start_to_end() {
+        let mut recorder = Recorder::new(4);
+        for step in 0..10 {
+            recorder.push(step);
+        }
+        assert_eq!(recorder.frames(), ["0", "4", "8"]);
+        assert_eq!(recorder.stride(), 4);
+    }
+
+    #[test]
+    fn frames_encode_as_animations() {
+        let frames = ["#.\n.@".to_string(), "\x1b[31m#\x1b[0m@\n..".to_string()];
+        let pictures = Pictures::new(&frames).unwrap();
+        assert_eq!((pictures.width, pictures.height), (2 * SCALE, 2 * SCALE));
+        // ' ', then '#', '.', '@' in order of appearance
+        assert_eq!(pictures.palette.len(), 4 * 3);
+        let first = pictures.pixels().next().unwrap();
+        assert_eq!(first.len(), 4 * SCALE as usize * SCALE as usize);
+        assert_eq!(first[0], 1);
+        assert_eq!(*first.last().unwrap(), 3);
+
+        let mut gif = Vec::new();
+        write_gif(&frames, &mut gif, 10).unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+        let mut apng = Vec::new();
+        write_apng(&frames, &mut apng, 10).unwrap();
+        assert!(apng.windows(4).any(|chunk| chunk == b"acTL"));
+        assert!(write_gif(&[], Vec::new(), 10).is_err());
+
+        assert_eq!("-".parse(), Ok(Output::Terminal));
+        assert_eq!(
+            "out/day6.png".parse(),
+            Ok(Output::Apng(PathBuf::from("out/day6.png")))
+        );
+        assert!("day6.txt".parse::<Output>().is_err());
+    }
+}
@@ -0,0 +1,228 @@
+//! Solving parts in a child process of their own, such that a part that loops
+//! forever or eats all memory is stopped and reported instead of holding up
+//! the run. The child is this binary again, asked to `--solve` a single part
+//! (see [`child`]), with its memory capped by `RLIMIT_AS`.
+
+use std::{
+    env, fs,
+    io::{self, Read, Write},
+    num::ParseIntError,
+    os::unix::process::{CommandExt, ExitStatusExt},
+    process::{Command, ExitStatus, Stdio},
+    str::FromStr,
+    thread,
+    time::{Duration, Instant},
+};
+
+use util::Outcome;
+
+use crate::year::{Answer, Year};
+
+/// How often to check whether a child has finished.
+const POLL: Duration = Duration::from_millis(5);
+
+#[derive(Debug, Copy, Clone)]
+pub struct Limits {
+    pub time: Duration,
+    /// In bytes, if capped.
+    pub memory: Option<u64>,
+}
+
+/// Solves `part` of `day` of `year` in a child process, stopping it once it
+/// runs over the time limit.
+pub fn solve(limits: Limits, year: u16, day: u8, part: u8, input: &str) -> Answer {
+    let failed = |e: io::Error| {
+        Answer::untimed(
+            part,
+            Outcome::Failed(format!("could not solve in a child: {}", e)),
+        )
+    };
+    let result = env::temp_dir().join(format!("aoc-all-result-{}", std::process::id()));
+    let _ = fs::remove_file(&result);
+    let exe = match env::current_exe() {
+        Ok(exe) => exe,
+        Err(e) => return failed(e),
+    };
+    let mut command = Command::new(exe);
+    command
+        .args([
+            "--solve",
+            &year.to_string(),
+            &day.to_string(),
+            &part.to_string(),
+        ])
+        .arg(&result)
+        .stdin(Stdio::piped())
+        // the answer comes back through `result`, whatever the solver prints would
+        // end up in the table
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped());
+    let memory = limits.memory;
+    // SAFETY: only async-signal-safe calls happen between fork and exec
+    unsafe {
+        command.pre_exec(move || {
+            // a group of its own, to stop whatever it runs along with it
+            if libc::setpgid(0, 0) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            if let Some(bytes) = memory {
+                let limit = libc::rlimit {
+                    rlim_cur: bytes,
+                    rlim_max: bytes,
+                };
+                if libc::setrlimit(libc::RLIMIT_AS, &limit) != 0 {
+                    return Err(io::Error::last_os_error());
+                }
+            }
+            Ok(())
+        });
+    }
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(e) => return failed(e),
+    };
+    let start = Instant::now();
+    // the child reads all of its input before solving, and it is closed once written
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(input.as_bytes());
+    }
+    let mut stderr = child.stderr.take().expect("stderr is piped");
+    let errors = thread::spawn(move || {
+        let mut errors = String::new();
+        let _ = stderr.read_to_string(&mut errors);
+        errors
+    });
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if start.elapsed() < limits.time => thread::sleep(POLL),
+            Ok(None) => {
+                // SAFETY: signals the group made for the child, which is still its own
+                unsafe { libc::kill(-(child.id() as libc::pid_t), libc::SIGKILL) };
+                let _ = child.wait();
+                let _ = errors.join();
+                return Answer::untimed(part, Outcome::TimedOut(limits.time));
+            }
+            Err(e) => return failed(e),
+        }
+    };
+    let errors = errors.join().unwrap_or_default();
+    let answer = match fs::read_to_string(&result) {
+        Ok(written) => read_answer(part, &written),
+        Err(_) => Answer::untimed(part, crashed(status, &errors)),
+    };
+    let _ = fs::remove_file(&result);
+    answer
+}
+
+/// What a process that ended without giving an outcome ran into, going by its stderr.
+pub fn crashed(status: ExitStatus, stderr: &str) -> Outcome {
+    // the allocator aborts with this once the memory limit is hit,
+    // and without a limit the kernel kills processes that use up all memory
+    if stderr.contains("memory allocation of") || status.signal() == Some(libc::SIGKILL) {
+        return Outcome::OutOfMemory;
+    }
+    match stderr.lines().rfind(|line| !line.trim().is_empty()) {
+        Some(reason) => Outcome::Failed(reason.trim().to_string()),
+        None => Outcome::Failed(status.to_string()),
+    }
+}
+
+/// The child side of [`solve`], given the arguments after `--solve`:
+/// solves the part with the input on stdin, writing how it went to the result file.
+pub fn child(years: &[Box<dyn Year>], args: &[String]) -> Result<(), String> {
+    let [year, day, part, result] = args else {
+        return Err("--solve needs a year, day, part and result file".to_string());
+    };
+    let (year, day, part) = (
+        number::<u16>(year)?,
+        number::<u8>(day)?,
+        number::<u8>(part)?,
+    );
+    let year = years
+        .iter()
+        .find(|y| y.number() == year)
+        .ok_or(format!("there are no solvers for {}", year))?;
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|e| format!("could not read input: {}", e))?;
+    let answer = year
+        .solve(day, Some(part), &input)
+        .into_iter()
+        .next()
+        .ok_or(format!("day {} has no part {}", day, part))?;
+    fs::write(result, write_answer(&answer)).map_err(|e| format!("could not write result: {}", e))
+}
+
+fn number<T: FromStr<Err = ParseIntError>>(s: &str) -> Result<T, String> {
+    s.parse()
+        .map_err(|e| format!("'{}' is not a number: {}", s, e))
+}
+
+/// The outcome on the first line, the time on the next and any text after.
+fn write_answer(answer: &Answer) -> String {
+    let (kind, text) = match &answer.outcome {
+        Outcome::Solved(answer) => ("solved", answer.as_str()),
+        Outcome::Unsolved => ("unsolved", ""),
+        Outcome::Failed(reason) => ("failed", reason.as_str()),
+        // from the binary of a year that runs as a process of its own
+        Outcome::OutOfMemory => ("oom", ""),
+        Outcome::TimedOut(_) => unreachable!("only the parent times parts out"),
+    };
+    let time = answer
+        .time
+        .map_or_else(|| "-".to_string(), |time| time.as_nanos().to_string());
+    format!("{}\n{}\n{}", kind, time, text)
+}
+
+fn read_answer(part: u8, written: &str) -> Answer {
+    let mut lines = written.splitn(3, '\n');
+    let (kind, time, text) = (lines.next(), lines.next(), lines.next().unwrap_or_default());
+    let outcome = match kind {
+        Some("solved") => Outcome::Solved(text.to_string()),
+        Some("unsolved") => Outcome::Unsolved,
+        Some("failed") => Outcome::Failed(text.to_string()),
+        Some("oom") => Outcome::OutOfMemory,
+        _ => Outcome::Failed(format!("unreadable result '{}'", written)),
+    };
+    Answer {
+        part,
+        outcome,
+        time: time
+            .and_then(|nanos| nanos.parse().ok())
+            .map(Duration::from_nanos),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(answer: Answer) {
+        let read = read_answer(answer.part, &write_answer(&answer));
+        assert_eq!(read.part, answer.part);
+        assert_eq!(read.outcome, answer.outcome);
+        assert_eq!(read.time, answer.time);
+    }
+
+    #[test]
+    fn answers_round_trip() {
+        round_trip(Answer {
+            part: 1,
+            outcome: Outcome::Solved("###\n# #\n".to_string()),
+            time: Some(Duration::from_nanos(1234)),
+        });
+        round_trip(Answer::untimed(2, Outcome::Solved(String::new())));
+        round_trip(Answer::untimed(2, Outcome::Unsolved));
+        round_trip(Answer::untimed(
+            1,
+            Outcome::Failed("boom\nat a.rs".to_string()),
+        ));
+        round_trip(Answer::untimed(1, Outcome::OutOfMemory));
+        assert_eq!(
+            read_answer(1, "garbage").outcome,
+            Outcome::Failed("unreadable result 'garbage'".to_string())
+        );
+    }
+}
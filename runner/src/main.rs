@@ -0,0 +1,280 @@
+//! Runs the solvers of every year through the entry point each year has,
+//! with one layout of inputs and one table of answers.
+//!
+//! ```text
+//! aoc-all [YEARS] [DAYS] [--part N] [--inputs DIR]
+//!     [--timeout SECONDS] [--memory MIB] [--in-process]
+//! ```
+//! `YEARS` and `DAYS` are a single number (`2024`, `6`) or a range
+//! (`2022..=2024`, `1..13`), defaulting to every year and every day.
+//! Inputs are read from `DIR/YEAR/DAY` (by default `inputs` in the root of the
+//! repository), falling back to where the year keeps them itself.
+//!
+//! Every answer is a row of the year, day, part, time to solve and answer,
+//! with any further lines of an answer indented below it. 2022 runs as its own
+//! process (see [`y2022`]), so its answers are not timed.
+//! A part that panics does not end the run: `todo!()` and `unimplemented!()`
+//! parts are reported as unsolved, other panics as failed with their message.
+//! The run ends with how many parts of every year were solved. It fails if a
+//! year could not be got ready to solve, such as 2022 not building.
+//!
+//! Every part is solved in a child process of its own (see [`limits`]), which
+//! is stopped after `--timeout` seconds (default 60) and may use up to
+//! `--memory` MiB (default 4096), such that a part that loops forever or eats
+//! all memory is reported as a timeout or OOM rather than holding up the run.
+//! `--in-process` solves parts in this process instead, without limits.
+
+mod limits;
+mod y2020;
+mod y2022;
+mod y2023;
+mod y2024;
+mod year;
+
+use std::{fs, ops::RangeInclusive, path::PathBuf, process::ExitCode, time::Duration};
+
+use limits::Limits;
+use util::Progress;
+use year::{Answer, Year, ROOT};
+
+const USAGE: &str = "usage: aoc-all [YEARS] [DAYS] [--part N] [--inputs DIR]
+               [--timeout SECONDS] [--memory MIB] [--in-process]";
+
+/// The first year of Advent of Code, telling years apart from days.
+const FIRST_YEAR: u16 = 2015;
+
+struct Args {
+    years: RangeInclusive<u16>,
+    days: RangeInclusive<u8>,
+    part: Option<u8>,
+    inputs: PathBuf,
+    /// `None` when solving in this process.
+    limits: Option<Limits>,
+}
+impl Args {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Args {
+            years: FIRST_YEAR..=u16::MAX,
+            days: 1..=25,
+            part: None,
+            inputs: PathBuf::from(format!("{}/inputs", ROOT)),
+            limits: Some(Limits {
+                time: Duration::from_secs(60),
+                memory: Some(4096 << 20),
+            }),
+        };
+        let mut in_process = false;
+        let mut args = args.peekable();
+        while let Some(arg) = args.next() {
+            let mut value = |flag: &str| args.next().ok_or(format!("{} needs a value", flag));
+            match arg.as_str() {
+                "--part" | "-p" => {
+                    parsed.part = Some(match value(&arg)?.as_str() {
+                        "1" => 1,
+                        "2" => 2,
+                        other => return Err(format!("part '{}' is not 1 or 2", other)),
+                    })
+                }
+                "--inputs" => parsed.inputs = value(&arg)?.into(),
+                "--timeout" => {
+                    let limits = parsed.limits.as_mut().expect("limited until parsed");
+                    limits.time = match value(&arg)?.parse().map(Duration::try_from_secs_f64) {
+                        Ok(Ok(time)) if !time.is_zero() => time,
+                        _ => return Err("--timeout needs a positive number".to_string()),
+                    }
+                }
+                "--memory" => {
+                    let limits = parsed.limits.as_mut().expect("limited until parsed");
+                    limits.memory = match value(&arg)?.parse::<u64>() {
+                        Ok(mib @ 1..) => Some(mib << 20),
+                        _ => return Err("--memory needs a positive number".to_string()),
+                    }
+                }
+                "--in-process" => in_process = true,
+                range if !range.starts_with('-') => {
+                    let numbers = parse_range(range)?;
+                    if *numbers.start() >= FIRST_YEAR {
+                        parsed.years = numbers;
+                    } else {
+                        parsed.days = u8::try_from(*numbers.start())
+                            .ok()
+                            .zip(u8::try_from(*numbers.end()).ok())
+                            .map(|(start, end)| start..=end)
+                            .ok_or(format!("'{}' is neither days nor years", range))?;
+                    }
+                }
+                unknown => return Err(format!("unknown argument '{}'", unknown)),
+            }
+        }
+        if in_process {
+            parsed.limits = None;
+        }
+        Ok(parsed)
+    }
+}
+
+/// Accepts `N`, `A..B` and `A..=B`.
+fn parse_range(range: &str) -> Result<RangeInclusive<u16>, String> {
+    let number = |s: &str| {
+        s.parse::<u16>()
+            .map_err(|e| format!("'{}' is not a number: {}", s, e))
+    };
+    let parsed = if let Some((start, end)) = range.split_once("..=") {
+        number(start)?..=number(end)?
+    } else if let Some((start, end)) = range.split_once("..") {
+        number(start)?..=number(end)?.saturating_sub(1)
+    } else {
+        number(range)?..=number(range)?
+    };
+    if parsed.is_empty() {
+        return Err(format!("'{}' is empty", range));
+    }
+    Ok(parsed)
+}
+
+fn main() -> ExitCode {
+    let years: [Box<dyn Year>; 4] = [
+        Box::new(y2020::Y2020),
+        Box::new(y2022::Y2022),
+        Box::new(y2023::Y2023),
+        Box::new(y2024::Y2024::new()),
+    ];
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if let Some(("--solve", solve)) = args
+        .split_first()
+        .map(|(first, rest)| (first.as_str(), rest))
+    {
+        return match limits::child(&years, solve) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        };
+    }
+    let args = match Args::parse(args.into_iter()) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    println!(
+        "{:>4} {:>3} {:>4} {:>10}  answer",
+        "year", "day", "part", "time"
+    );
+    let mut status = ExitCode::SUCCESS;
+    let mut progress = Vec::new();
+    for year in years.iter().filter(|y| args.years.contains(&y.number())) {
+        let mut year_progress = Progress::default();
+        let mut prepared = None;
+        for day in year.days() {
+            if !args.days.contains(&day) {
+                continue;
+            }
+            let Some(input) = input(&args, year.as_ref(), day) else {
+                continue;
+            };
+            // only once there is something to solve
+            if let Err(e) = prepared.get_or_insert_with(|| year.prepare()) {
+                eprintln!("{}: {}", year.number(), e);
+                status = ExitCode::FAILURE;
+                break;
+            }
+            let answers = match args.limits {
+                Some(limits) => year
+                    .parts(day)
+                    .into_iter()
+                    .filter(|part| args.part.is_none_or(|p| p == *part))
+                    .map(|part| limits::solve(limits, year.number(), day, part, &input))
+                    .collect(),
+                None => year.solve(day, args.part, &input),
+            };
+            for answer in answers {
+                print_answer(year.number(), day, &answer);
+                year_progress.add(&answer.outcome);
+            }
+        }
+        progress.push((year.number(), year_progress));
+    }
+    print_progress(&progress);
+    status
+}
+
+/// The input of `day` from `--inputs`, or else from where the year keeps it.
+fn input(args: &Args, year: &dyn Year, day: u8) -> Option<String> {
+    let unified = args
+        .inputs
+        .join(year.number().to_string())
+        .join(day.to_string());
+    let own = year.own_input(day);
+    let input = fs::read_to_string(&unified).or_else(|_| fs::read_to_string(&own));
+    if input.is_err() {
+        eprintln!(
+            "{} day {}: no input at {} or {}",
+            year.number(),
+            day,
+            unified.display(),
+            own.display()
+        );
+    }
+    input.ok()
+}
+
+/// A row per year and one for all of them together.
+fn print_progress(progress: &[(u16, Progress)]) {
+    println!("\n{:>5}  progress", "year");
+    let mut total = Progress::default();
+    for (year, year_progress) in progress {
+        println!("{:>5}  {}", year, year_progress);
+        total += *year_progress;
+    }
+    println!("{:>5}  {}", "total", total);
+}
+
+fn print_answer(year: u16, day: u8, answer: &Answer) {
+    let time = answer
+        .time
+        .map_or_else(|| "-".to_string(), |time| format!("{:.1?}", time));
+    let text = answer.outcome.to_string();
+    let mut lines = text.lines();
+    println!(
+        "{:>4} {:>3} {:>4} {:>10}  {}",
+        year,
+        day,
+        answer.part,
+        time,
+        lines.next().unwrap_or_default()
+    );
+    for line in lines {
+        println!("{:>25}{}", "", line);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges_parse() {
+        assert_eq!(parse_range("6"), Ok(6..=6));
+        assert_eq!(parse_range("1..13"), Ok(1..=12));
+        assert_eq!(parse_range("1..=13"), Ok(1..=13));
+        assert_eq!(parse_range("2022..2024"), Ok(2022..=2023));
+        assert!(parse_range("3..3").is_err());
+        assert!(parse_range("3..1").is_err());
+        assert!(parse_range("six").is_err());
+    }
+
+    #[test]
+    fn years_are_told_apart_from_days() {
+        let parse = |args: &[&str]| Args::parse(args.iter().map(ToString::to_string));
+        let args = parse(&["2015", "1..=12"]).unwrap();
+        assert_eq!((args.years, args.days), (2015..=2015, 1..=12));
+        let args = parse(&["14"]).unwrap();
+        assert_eq!((args.years, args.days), (FIRST_YEAR..=u16::MAX, 14..=14));
+        assert!(parse(&["2014"]).is_err());
+        assert!(parse(&["2014..2016"]).is_err());
+    }
+}
@@ -0,0 +1,38 @@
+//! 2020, through the table of days its build script generates.
+
+use std::path::PathBuf;
+
+use util::Outcome;
+
+use crate::year::{Answer, Year, ROOT};
+
+pub struct Y2020;
+impl Year for Y2020 {
+    fn number(&self) -> u16 {
+        2020
+    }
+
+    fn days(&self) -> Vec<u8> {
+        aoc2020::DAYS.iter().map(|(day, _)| *day).collect()
+    }
+
+    fn parts(&self, day: u8) -> Vec<u8> {
+        let parts = aoc2020::DAYS.iter().find(|(other, _)| *other == day);
+        parts.map_or_else(Vec::new, |(_, parts)| (1..).take(parts.len()).collect())
+    }
+
+    fn own_input(&self, day: u8) -> PathBuf {
+        PathBuf::from(format!("{}/aoc2020/src/day_{}/input.txt", ROOT, day))
+    }
+
+    fn solve(&self, day: u8, part: Option<u8>, input: &str) -> Vec<Answer> {
+        let Some((_, parts)) = aoc2020::DAYS.iter().find(|(other, _)| *other == day) else {
+            return Vec::new();
+        };
+        (1..)
+            .zip(parts)
+            .filter(|(n, _)| part.is_none_or(|p| p == *n))
+            .map(|(n, solver)| Answer::timed(n, || Outcome::of(|| solver(input))))
+            .collect()
+    }
+}
@@ -0,0 +1,184 @@
+//! 2022, by running its binary in its `answers` mode. Its crate depends on
+//! GUI crates, so it is built on its own rather than linked in. It is built
+//! before solving, such that building does not count against the limits of parts.
+//! It is built with a toolchain of its own, as its dependencies do not build on
+//! whatever toolchain the runner happens to be built with.
+
+use std::{
+    env, fs,
+    path::PathBuf,
+    process::{Command, ExitStatus},
+};
+
+use util::Outcome;
+
+use crate::{
+    limits,
+    year::{Answer, Year, ROOT},
+};
+
+/// Its days have two parts, whether or not they give both answers.
+const PARTS: [u8; 2] = [1, 2];
+
+/// What 2022 is built with, see [`Y2022::prepare`].
+const TOOLCHAIN: &str = "stable";
+
+pub struct Y2022;
+impl Y2022 {
+    /// The answers the binary prints for `input`: `PART N: ` starts one,
+    /// lines starting with a tab continue it. Anything else was printed by solvers.
+    /// A panic ends the process, so it is the outcome of every part.
+    fn run(&self, day: u8, input: &str) -> Result<Vec<String>, Outcome> {
+        let dir = env::temp_dir().join(format!("aoc-all-2022-{}", std::process::id()));
+        let failed = |e: std::io::Error| Outcome::Failed(e.to_string());
+        fs::create_dir_all(dir.join("inputs")).map_err(failed)?;
+        fs::write(dir.join("inputs").join(format!("day_{}.txt", day)), input).map_err(failed)?;
+        let output = Command::new(format!("{}/aoc2022/target/release/aoc2022", ROOT))
+            .args([&day.to_string(), "answers"])
+            .current_dir(&dir)
+            .output();
+        let _ = fs::remove_dir_all(&dir);
+        let output = output.map_err(|e| Outcome::Failed(format!("could not run 2022: {}", e)))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(panic_outcome(output.status, &stderr));
+        }
+        let mut answers: Vec<String> = Vec::new();
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            if let Some(answer) = line
+                .strip_prefix("PART ")
+                .and_then(|rest| rest.split_once(": "))
+                .map(|(_, answer)| answer)
+            {
+                answers.push(answer.to_string());
+            } else if let (Some(continued), Some(answer)) =
+                (line.strip_prefix('\t'), answers.last_mut())
+            {
+                answer.push('\n');
+                answer.push_str(continued);
+            }
+        }
+        Ok(answers)
+    }
+}
+impl Year for Y2022 {
+    fn number(&self) -> u16 {
+        2022
+    }
+
+    /// Builds with the `cargo` of rustup rather than `$CARGO`, which is the cargo
+    /// of the toolchain the runner was started with (such as `cargo +nightly run`).
+    fn prepare(&self) -> Result<(), String> {
+        let status = Command::new("cargo")
+            .env("RUSTUP_TOOLCHAIN", TOOLCHAIN)
+            .args(["build", "--quiet", "--release", "--manifest-path"])
+            .arg(format!("{}/aoc2022/Cargo.toml", ROOT))
+            .status()
+            .map_err(|e| format!("could not build 2022: {}", e))?;
+        if !status.success() {
+            return Err(format!("could not build 2022: {}", status));
+        }
+        Ok(())
+    }
+
+    /// Every `day_N` module of its sources.
+    fn days(&self) -> Vec<u8> {
+        let Ok(entries) = fs::read_dir(format!("{}/aoc2022/src", ROOT)) else {
+            return Vec::new();
+        };
+        let mut days = entries
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                let name = name.strip_suffix(".rs").unwrap_or(&name);
+                name.strip_prefix("day_")?.parse().ok()
+            })
+            .collect::<Vec<u8>>();
+        days.sort_unstable();
+        days
+    }
+
+    fn parts(&self, _day: u8) -> Vec<u8> {
+        PARTS.to_vec()
+    }
+
+    fn own_input(&self, day: u8) -> PathBuf {
+        PathBuf::from(format!("{}/aoc2022/inputs/day_{}.txt", ROOT, day))
+    }
+
+    fn solve(&self, day: u8, part: Option<u8>, input: &str) -> Vec<Answer> {
+        let wanted = PARTS.into_iter().filter(|n| part.is_none_or(|p| p == *n));
+        match self.run(day, input) {
+            Ok(answers) => wanted
+                .map(|n| match answers.get(n as usize - 1) {
+                    Some(answer) => Answer::untimed(n, Outcome::Solved(answer.clone())),
+                    None if answers.is_empty() => {
+                        Answer::untimed(n, Outcome::Failed("only prints its answers".to_string()))
+                    }
+                    None => Answer::untimed(n, Outcome::Unsolved),
+                })
+                .collect(),
+            Err(outcome) => wanted
+                .map(|n| Answer::untimed(n, outcome.clone()))
+                .collect(),
+        }
+    }
+}
+
+/// What the stderr of a failed run says went wrong. Panics are reported as
+/// `thread 'main' panicked at FILE:LINE:COLUMN:` with the message on the next line.
+fn panic_outcome(status: ExitStatus, stderr: &str) -> Outcome {
+    let mut lines = stderr
+        .lines()
+        .skip_while(|line| !line.contains(" panicked at "));
+    match (lines.next(), lines.next()) {
+        (_, Some(message))
+            if message.starts_with("not yet implemented")
+                || message.starts_with("not implemented") =>
+        {
+            Outcome::Unsolved
+        }
+        (Some(at), Some(message)) => {
+            let location = at.rsplit(" panicked at ").next().unwrap_or(at);
+            Outcome::Failed(format!(
+                "{} (at {})",
+                message,
+                location.trim_end_matches(':')
+            ))
+        }
+        _ => limits::crashed(status, stderr),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::process::ExitStatusExt;
+
+    use super::*;
+
+    /// How a process that exited with 101 panicking with `message` at `src/day_6.rs:15:5` ends.
+    fn panicked(message: &str) -> Outcome {
+        let stderr = format!(
+            "answer (part 1) = 7\nthread 'main' panicked at src/day_6.rs:15:5:\n{}\n\
+             note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n",
+            message
+        );
+        panic_outcome(ExitStatus::from_raw(101 << 8), &stderr)
+    }
+
+    #[test]
+    fn panics_are_told_apart() {
+        assert_eq!(panicked("not yet implemented"), Outcome::Unsolved);
+        assert_eq!(panicked("not implemented: part 2"), Outcome::Unsolved);
+        assert_eq!(
+            panicked("called `Option::unwrap()` on a `None` value"),
+            Outcome::Failed(
+                "called `Option::unwrap()` on a `None` value (at src/day_6.rs:15:5)".to_string()
+            )
+        );
+        assert_eq!(
+            panic_outcome(ExitStatus::from_raw(1 << 8), "Error: no input\n"),
+            Outcome::Failed("Error: no input".to_string())
+        );
+    }
+}
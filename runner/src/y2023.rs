@@ -0,0 +1,49 @@
+//! 2023, through the macros its runner finds the crates of its days with.
+
+use std::path::PathBuf;
+
+use glue::SolverFn;
+use macros::{all_days, map_to_part_functions, use_all_days};
+use rest::*;
+use util::Outcome;
+
+use crate::year::{Answer, Year, ROOT};
+
+use_all_days!();
+
+const DAYS: &[u8] = &all_days!();
+
+pub struct Y2023;
+impl Year for Y2023 {
+    fn number(&self) -> u16 {
+        2023
+    }
+
+    fn days(&self) -> Vec<u8> {
+        DAYS.to_vec()
+    }
+
+    fn parts(&self, day: u8) -> Vec<u8> {
+        if !DAYS.contains(&day) {
+            return Vec::new();
+        }
+        let parts: &[SolverFn] = map_to_part_functions!();
+        (1..).take(parts.len()).collect()
+    }
+
+    fn own_input(&self, day: u8) -> PathBuf {
+        PathBuf::from(format!("{}/aoc2023/txt_input/day_{:0>2}.txt", ROOT, day))
+    }
+
+    fn solve(&self, day: u8, part: Option<u8>, input: &str) -> Vec<Answer> {
+        if !DAYS.contains(&day) {
+            return Vec::new();
+        }
+        let parts: &[SolverFn] = map_to_part_functions!();
+        (1..)
+            .zip(parts)
+            .filter(|(n, _)| part.is_none_or(|p| p == *n))
+            .map(|(n, solver)| Answer::timed(n, || Outcome::of(|| solver(input))))
+            .collect()
+    }
+}
@@ -0,0 +1,58 @@
+//! 2024, through the registry of its day crates.
+
+use std::path::PathBuf;
+
+use util::{isolate, Outcome, Registry};
+
+use crate::year::{Answer, Year, ROOT};
+
+pub struct Y2024 {
+    registry: Registry,
+}
+impl Y2024 {
+    pub fn new() -> Self {
+        Y2024 {
+            registry: aoc2024::registry(),
+        }
+    }
+}
+impl Year for Y2024 {
+    fn number(&self) -> u16 {
+        2024
+    }
+
+    fn days(&self) -> Vec<u8> {
+        self.registry.select(1..=25).map(|(day, _)| day).collect()
+    }
+
+    fn parts(&self, day: u8) -> Vec<u8> {
+        let solvers = self.registry.get(day);
+        solvers.map_or_else(Vec::new, |solvers| solvers.parts().collect())
+    }
+
+    fn own_input(&self, day: u8) -> PathBuf {
+        PathBuf::from(format!("{}/aoc2024/inputs/{}", ROOT, day))
+    }
+
+    /// Every part parses the input itself, for timings like those of other years.
+    fn solve(&self, day: u8, part: Option<u8>, input: &str) -> Vec<Answer> {
+        let Some(solvers) = self.registry.get(day) else {
+            return Vec::new();
+        };
+        solvers
+            .parts()
+            .filter(|n| part.is_none_or(|p| p == *n))
+            .map(|n| {
+                Answer::timed(n, || {
+                    match isolate(|| self.registry.answer(day, n, input)) {
+                        Ok(answer) => match answer.expect("part is registered") {
+                            Ok(answer) => Outcome::Solved(answer),
+                            Err(e) => Outcome::Failed(e.to_string()),
+                        },
+                        Err(outcome) => outcome,
+                    }
+                })
+            })
+            .collect()
+    }
+}
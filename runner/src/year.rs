@@ -0,0 +1,63 @@
+//! What the runner needs of a year, whatever its own entry point is like.
+
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use util::Outcome;
+
+/// The root of the repository, holding a directory per year.
+pub const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// How a single part went.
+pub struct Answer {
+    pub part: u8,
+    pub outcome: Outcome,
+    /// How long solving took, if the year solves in this process.
+    pub time: Option<Duration>,
+}
+impl Answer {
+    /// Times `solve` as the outcome of `part`.
+    pub fn timed(part: u8, solve: impl FnOnce() -> Outcome) -> Self {
+        let start = Instant::now();
+        let outcome = solve();
+        Answer {
+            part,
+            outcome,
+            time: Some(start.elapsed()),
+        }
+    }
+
+    /// An outcome that was not timed.
+    pub fn untimed(part: u8, outcome: Outcome) -> Self {
+        Answer {
+            part,
+            outcome,
+            time: None,
+        }
+    }
+}
+
+/// The solvers of a single year.
+pub trait Year {
+    fn number(&self) -> u16;
+
+    /// Days with solvers, in order.
+    fn days(&self) -> Vec<u8>;
+
+    /// The parts `day` has solvers for, in order.
+    fn parts(&self, day: u8) -> Vec<u8>;
+
+    /// Gets the solvers ready to run, before any part is solved.
+    fn prepare(&self) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Where the year keeps the input of `day` itself.
+    fn own_input(&self, day: u8) -> PathBuf;
+
+    /// Answers to the parts of `day` (only `part` if given) in order.
+    /// A panic in one part does not keep the others from being solved.
+    fn solve(&self, day: u8, part: Option<u8>, input: &str) -> Vec<Answer>;
+}